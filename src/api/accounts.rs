@@ -0,0 +1,74 @@
+use crate::api::balance::AccountBalanceDto;
+use crate::api::db::get_db_connection;
+use anyhow::{bail, Context, Result};
+use flutter_rust_bridge::frb;
+use minotari_wallet::db::AccountRow;
+use minotari_wallet::{get_accounts, get_balance as get_wallet_balance};
+use r2d2_sqlite::rusqlite::Connection;
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct AccountDto {
+    pub account_id: i64,
+    pub wallet_name: String,
+    pub balance: AccountBalanceDto,
+}
+
+#[frb]
+pub fn list_accounts(wallet_name: Option<String>) -> Result<Vec<AccountDto>> {
+    let mut conn = get_db_connection()?;
+    let accounts = get_accounts(&mut conn, wallet_name.as_deref())?;
+
+    accounts
+        .into_iter()
+        .map(|account| {
+            let balance = get_wallet_balance(&mut conn, account.id)?;
+            Ok(AccountDto {
+                account_id: account.id,
+                wallet_name: account.friendly_name,
+                balance: balance.into(),
+            })
+        })
+        .collect()
+}
+
+/// Picks `account_id` out of the wallet's accounts, or the first account when none is given.
+pub(crate) fn resolve_account(
+    conn: &mut Connection,
+    wallet_name: Option<&str>,
+    account_id: Option<i64>,
+) -> Result<AccountRow> {
+    let accounts = get_accounts(conn, wallet_name)?;
+    match account_id {
+        Some(account_id) => accounts
+            .into_iter()
+            .find(|a| a.id == account_id)
+            .with_context(|| format!("Account {} not found for this wallet", account_id)),
+        None => accounts
+            .into_iter()
+            .next()
+            .context("No accounts found for this wallet"),
+    }
+}
+
+/// Like [`resolve_account`], but fails when another account shares the account's name.
+///
+/// The transaction sender selects accounts by name, so an account is only handed to it when its
+/// name points back at it alone.
+pub(crate) fn resolve_unique_account(
+    conn: &mut Connection,
+    wallet_name: Option<&str>,
+    account_id: Option<i64>,
+) -> Result<AccountRow> {
+    let account = resolve_account(conn, wallet_name, account_id)?;
+    let namesakes = get_accounts(conn, Some(&account.friendly_name))?.len();
+    if namesakes > 1 {
+        bail!(
+            "Account {} shares the name '{}' with {} other account(s) and can't be selected",
+            account.id,
+            account.friendly_name,
+            namesakes - 1
+        );
+    }
+    Ok(account)
+}
@@ -1,21 +1,219 @@
-use crate::api::{db::get_db_connection, network::parse_network};
-use anyhow::{Context, Result};
+use crate::api::accounts::resolve_account;
+use crate::api::db::get_db_connection;
+use crate::api::network::{parse_network, resolve_wallet_network};
+use anyhow::Result;
 use flutter_rust_bridge::frb;
-use minotari_wallet::get_accounts;
+use tari_common_types::emoji::EMOJI;
+use tari_common_types::tari_address::{TariAddress, TariAddressError, TariAddressFeatures};
+use tari_utilities::hex::{to_hex, Hex};
+use thiserror::Error;
+
+// One emoji per byte: network, features, keys and checksum, plus any payment id.
+const SINGLE_ADDRESS_EMOJI_LEN: usize = 1 + 1 + 32 + 1;
+const DUAL_ADDRESS_EMOJI_LEN: usize = 1 + 1 + 32 + 32 + 1;
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct WalletAddressDto {
+    pub base58: String,
+    pub emoji: String,
+}
+
+#[frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressFormat {
+    Base58,
+    Emoji,
+    Hex,
+}
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct AddressInspectionDto {
+    pub is_valid: bool,
+    pub error: Option<String>,
+    pub format: AddressFormat,
+    pub checksum_valid: bool,
+    pub network: Option<String>,
+    /// `None` when no expected network was given or the address could not be parsed.
+    pub network_matches: Option<bool>,
+    pub is_dual_address: bool,
+    pub supports_one_sided: bool,
+    pub supports_interactive: bool,
+    pub has_payment_id: bool,
+    /// The embedded payment id as text, or hex when it is not valid UTF-8.
+    pub payment_id: Option<String>,
+    pub payment_id_hex: Option<String>,
+    pub public_view_key_hex: Option<String>,
+    pub public_spend_key_hex: Option<String>,
+    pub base58: Option<String>,
+    pub emoji: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum AddressError {
+    #[error("Address is empty")]
+    Empty,
+
+    #[error("Address checksum is invalid")]
+    InvalidChecksum,
+
+    #[error("Emoji ID contains an unknown character '{character}' at position {position}")]
+    InvalidEmoji { position: usize, character: String },
+
+    #[error("Emoji ID is too short: {length} emojis, expected at least {expected}")]
+    TruncatedEmoji { length: usize, expected: usize },
+
+    #[error("Invalid {format:?} address: {reason}")]
+    Invalid {
+        format: AddressFormat,
+        reason: String,
+    },
+}
 
 #[frb]
 pub fn get_address(
     wallet_name: Option<String>,
+    account_id: Option<i64>,
     passphrase: Option<String>,
     network: Option<String>,
-) -> Result<String> {
-    let network = parse_network(network)?;
+) -> Result<WalletAddressDto> {
     let mut conn = get_db_connection()?;
-    let accounts = &get_accounts(&mut conn, wallet_name.as_deref())?;
-    let account = accounts
-        .first()
-        .context("No accounts found for this wallet")?;
+    let account = resolve_account(&mut conn, wallet_name.as_deref(), account_id)?;
+    let network = resolve_wallet_network(&conn, &account.friendly_name, network)?;
     let address = account.get_address(network, passphrase.as_deref().unwrap_or(""))?;
 
-    Ok(address.to_base58())
+    Ok(WalletAddressDto {
+        base58: address.to_base58(),
+        emoji: address.to_emoji_string(),
+    })
+}
+
+/// Parses a base58, emoji id or hex address and describes it. Never fails, so it can run on every
+/// keystroke; problems are reported through `is_valid` and `error`.
+#[frb]
+pub fn inspect_address(input: String, expected_network: Option<String>) -> AddressInspectionDto {
+    let format = detect_address_format(&input);
+    let mut result = AddressInspectionDto {
+        is_valid: false,
+        error: None,
+        format,
+        checksum_valid: false,
+        network: None,
+        network_matches: None,
+        is_dual_address: false,
+        supports_one_sided: false,
+        supports_interactive: false,
+        has_payment_id: false,
+        payment_id: None,
+        payment_id_hex: None,
+        public_view_key_hex: None,
+        public_spend_key_hex: None,
+        base58: None,
+        emoji: None,
+    };
+
+    let address = match parse_address(&input) {
+        Ok(address) => address,
+        Err(e) => {
+            result.error = Some(e.to_string());
+            return result;
+        }
+    };
+
+    let features = address.features();
+    let payment_id_bytes = address.get_payment_id_user_data_bytes();
+
+    result.is_valid = true;
+    result.checksum_valid = true;
+    result.network = Some(address.network().to_string());
+    result.network_matches = expected_network
+        .map(|n| parse_network(Some(n)))
+        .transpose()
+        .ok()
+        .flatten()
+        .map(|expected| expected == address.network());
+    result.is_dual_address = address.public_view_key().is_some();
+    result.supports_one_sided = features.contains(TariAddressFeatures::ONE_SIDED);
+    result.supports_interactive = features.contains(TariAddressFeatures::INTERACTIVE);
+    result.has_payment_id = features.contains(TariAddressFeatures::PAYMENT_ID);
+    if !payment_id_bytes.is_empty() {
+        result.payment_id_hex = Some(to_hex(&payment_id_bytes));
+        result.payment_id = Some(
+            String::from_utf8(payment_id_bytes.clone())
+                .unwrap_or_else(|_| to_hex(&payment_id_bytes)),
+        );
+    }
+    result.public_view_key_hex = address.public_view_key().map(|k| k.to_hex());
+    result.public_spend_key_hex = Some(address.public_spend_key().to_hex());
+    result.base58 = Some(address.to_base58());
+    result.emoji = Some(address.to_emoji_string());
+
+    result
+}
+
+pub(crate) fn parse_address(input: &str) -> Result<TariAddress, AddressError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(AddressError::Empty);
+    }
+
+    let format = detect_address_format(input);
+    if format == AddressFormat::Emoji {
+        check_emoji_id(input)?;
+    }
+
+    let parsed = match format {
+        AddressFormat::Base58 => TariAddress::from_base58(input),
+        AddressFormat::Emoji => {
+            let emojis: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+            TariAddress::from_emoji_string(&emojis)
+        }
+        AddressFormat::Hex => {
+            TariAddress::from_hex(input).or_else(|e| TariAddress::from_base58(input).map_err(|_| e))
+        }
+    };
+
+    parsed.map_err(|e| match e {
+        TariAddressError::InvalidChecksum => AddressError::InvalidChecksum,
+        e => AddressError::Invalid {
+            format,
+            reason: e.to_string(),
+        },
+    })
+}
+
+/// Catches the common emoji id mistakes up front, since the parser only reports a generic error.
+fn check_emoji_id(input: &str) -> Result<(), AddressError> {
+    let emojis: Vec<char> = input.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if let Some((position, character)) =
+        emojis.iter().enumerate().find(|(_, c)| !EMOJI.contains(*c))
+    {
+        return Err(AddressError::InvalidEmoji {
+            position: position + 1,
+            character: character.to_string(),
+        });
+    }
+
+    let length = emojis.len();
+    let expected = match length {
+        l if l < SINGLE_ADDRESS_EMOJI_LEN => SINGLE_ADDRESS_EMOJI_LEN,
+        l if l > SINGLE_ADDRESS_EMOJI_LEN && l < DUAL_ADDRESS_EMOJI_LEN => DUAL_ADDRESS_EMOJI_LEN,
+        _ => return Ok(()),
+    };
+
+    Err(AddressError::TruncatedEmoji { length, expected })
+}
+
+fn detect_address_format(input: &str) -> AddressFormat {
+    let input = input.trim();
+    if !input.is_ascii() {
+        AddressFormat::Emoji
+    } else if input.len() % 2 == 0 && input.chars().all(|c| c.is_ascii_hexdigit()) {
+        // Base58 can in principle be all hex digits, so parsing falls back to it.
+        AddressFormat::Hex
+    } else {
+        AddressFormat::Base58
+    }
 }
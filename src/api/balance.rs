@@ -1,10 +1,30 @@
-use crate::api::db::get_db_connection;
-use anyhow::{Context, Result};
+use crate::api::network::resolve_wallet_network;
+use crate::api::network_profiles::resolve_network_profile;
+use crate::api::outputs::{load_balance_movements, load_unspent_outputs, scanned_tip_height};
+use crate::api::{accounts::resolve_account, db::get_db_connection};
+use crate::frb_generated::StreamSink;
+use anyhow::Result;
 use flutter_rust_bridge::frb;
-use minotari_wallet::{get_accounts, get_balance as get_wallet_balance};
+use minotari_wallet::db::get_displayed_transactions_paginated;
+use minotari_wallet::get_balance as get_wallet_balance;
+use minotari_wallet::transactions::{TransactionDirection, TransactionDisplayStatus};
+use minotari_wallet::DisplayedTransaction;
+use once_cell::sync::Lazy;
+use r2d2_sqlite::rusqlite::Connection;
+use std::time::Duration;
+use tokio::sync::watch;
+
+// Locked outputs are released by time rather than by an event, so watchers also re-check
+// on this interval.
+const BALANCE_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+// Transactions are read in pages of this size while looking for unconfirmed sends.
+const TRANSACTION_PAGE_SIZE: i64 = 200;
+
+static BALANCE_CHANGES: Lazy<watch::Sender<u64>> = Lazy::new(|| watch::channel(0).0);
 
 #[frb]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountBalanceDto {
     pub total: u64,
     pub unconfirmed: u64,
@@ -23,13 +43,280 @@ impl From<minotari_wallet::db::AccountBalance> for AccountBalanceDto {
     }
 }
 
+impl AccountBalanceDto {
+    pub(crate) fn accumulate(&mut self, other: &AccountBalanceDto) {
+        self.total = self.total.saturating_add(other.total);
+        self.unconfirmed = self.unconfirmed.saturating_add(other.unconfirmed);
+        self.locked = self.locked.saturating_add(other.locked);
+        self.available = self.available.saturating_add(other.available);
+    }
+}
+
 #[frb]
-pub fn get_balance(wallet_name: Option<String>) -> Result<AccountBalanceDto> {
+pub fn get_balance(
+    wallet_name: Option<String>,
+    account_id: Option<i64>,
+) -> Result<AccountBalanceDto> {
     let mut conn = get_db_connection()?;
-    let accounts = &get_accounts(&mut conn, wallet_name.as_deref())?;
-    let account = accounts
-        .first()
-        .context("No accounts found for this wallet")?;
+    let account = resolve_account(&mut conn, wallet_name.as_deref(), account_id)?;
     let agg_result = get_wallet_balance(&mut conn, account.id)?;
     Ok(agg_result.into())
 }
+
+/// One output or transaction contributing to a balance bucket.
+#[frb]
+#[derive(Clone, Debug)]
+pub struct BalanceEntryDto {
+    pub amount: u64,
+    pub output_hash: Option<String>,
+    pub transaction_id: Option<String>,
+    /// Block height from which the funds can be spent, when that depends on the chain.
+    pub spendable_at_height: Option<u64>,
+    /// RFC 3339 time from which the funds can be spent, when that depends on the clock.
+    pub spendable_at: Option<String>,
+}
+
+#[frb]
+#[derive(Clone, Debug, Default)]
+pub struct BalanceBucketDto {
+    pub total: u64,
+    pub entries: Vec<BalanceEntryDto>,
+}
+
+impl BalanceBucketDto {
+    fn push(&mut self, entry: BalanceEntryDto) {
+        self.total = self.total.saturating_add(entry.amount);
+        self.entries.push(entry);
+    }
+}
+
+/// Explains where an account's funds are and when unavailable funds become spendable.
+///
+/// Every unspent output lands in exactly one of `available`, `pending_incoming`,
+/// `locked`, `immature_coinbase` or `time_locked`. `pending_outgoing` lists unconfirmed sends,
+/// whose inputs are the outputs in `locked`.
+#[frb]
+#[derive(Clone, Debug)]
+pub struct BalanceBreakdownDto {
+    pub account_id: i64,
+    pub tip_height: u64,
+    pub confirmation_window: u64,
+    pub available: u64,
+    pub pending_incoming: BalanceBucketDto,
+    pub pending_outgoing: BalanceBucketDto,
+    pub locked: BalanceBucketDto,
+    pub immature_coinbase: BalanceBucketDto,
+    pub time_locked: BalanceBucketDto,
+}
+
+#[frb]
+pub fn get_balance_breakdown(
+    wallet_name: Option<String>,
+    account_id: Option<i64>,
+) -> Result<BalanceBreakdownDto> {
+    let mut conn = get_db_connection()?;
+    let account = resolve_account(&mut conn, wallet_name.as_deref(), account_id)?;
+    let network = resolve_wallet_network(&conn, &account.friendly_name, None)?;
+    let confirmation_window = resolve_network_profile(&conn, network)?.confirmation_window;
+    let tip_height = scanned_tip_height(&conn, account.id)?;
+
+    let mut breakdown = BalanceBreakdownDto {
+        account_id: account.id,
+        tip_height,
+        confirmation_window,
+        available: 0,
+        pending_incoming: BalanceBucketDto::default(),
+        pending_outgoing: BalanceBucketDto::default(),
+        locked: BalanceBucketDto::default(),
+        immature_coinbase: BalanceBucketDto::default(),
+        time_locked: BalanceBucketDto::default(),
+    };
+
+    for output in load_unspent_outputs(&conn, account.id)? {
+        let entry =
+            |spendable_at_height: Option<u64>, spendable_at: Option<String>| BalanceEntryDto {
+                amount: output.value,
+                output_hash: Some(output.output_hash.clone()),
+                transaction_id: None,
+                spendable_at_height,
+                spendable_at,
+            };
+
+        if output.is_locked() {
+            breakdown.locked.push(entry(None, output.lock_expires_at()));
+        } else if !output.is_confirmed(tip_height, confirmation_window) {
+            let height = output.confirmed_at_height(confirmation_window);
+            breakdown.pending_incoming.push(entry(Some(height), None));
+        } else if output.is_coinbase() && output.maturity_height > tip_height {
+            let height = output.maturity_height;
+            breakdown.immature_coinbase.push(entry(Some(height), None));
+        } else if output.spendable_height() > tip_height {
+            let height = output.spendable_height();
+            breakdown.time_locked.push(entry(Some(height), None));
+        } else {
+            breakdown.available = breakdown.available.saturating_add(output.value);
+        }
+    }
+
+    for transaction in load_pending_outgoing(&conn, account.id, confirmation_window)? {
+        let mined_height = transaction.blockchain.block_height;
+        breakdown.pending_outgoing.push(BalanceEntryDto {
+            amount: transaction.amount,
+            output_hash: None,
+            transaction_id: Some(transaction.id),
+            spendable_at_height: (mined_height > 0)
+                .then(|| mined_height.saturating_add(confirmation_window)),
+            spendable_at: None,
+        });
+    }
+
+    Ok(breakdown)
+}
+
+#[frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BalanceGranularity {
+    Block,
+    Day,
+}
+
+/// The account's confirmed on-chain balance at the end of a block or day.
+#[frb]
+#[derive(Clone, Debug)]
+pub struct BalancePointDto {
+    /// The last block included in this point.
+    pub block_height: u64,
+    /// `YYYY-MM-DD` (UTC) for daily points; for block points, the block time when known.
+    pub date: Option<String>,
+    pub balance: u64,
+    pub received: u64,
+    pub spent: u64,
+}
+
+/// Rebuilds the running balance from the scanned outputs and inputs.
+///
+/// Only heights or days where the balance moved get a point; funds moved before
+/// `from_height` are carried into the first point's balance.
+#[frb]
+pub fn get_balance_history(
+    wallet_name: Option<String>,
+    account_id: Option<i64>,
+    from_height: Option<u64>,
+    to_height: Option<u64>,
+    granularity: BalanceGranularity,
+) -> Result<Vec<BalancePointDto>> {
+    let mut conn = get_db_connection()?;
+    let account = resolve_account(&mut conn, wallet_name.as_deref(), account_id)?;
+    let from_height = from_height.unwrap_or(0);
+    let to_height = to_height.unwrap_or(u64::MAX);
+
+    let mut balance: u64 = 0;
+    let mut points: Vec<BalancePointDto> = Vec::new();
+    for movement in load_balance_movements(&conn, account.id)? {
+        if movement.height > to_height {
+            break;
+        }
+        balance = balance
+            .saturating_add(movement.credit)
+            .saturating_sub(movement.debit);
+        if movement.height < from_height {
+            continue;
+        }
+
+        let date = match granularity {
+            BalanceGranularity::Block => movement.timestamp.map(|t| t.to_rfc3339()),
+            BalanceGranularity::Day => movement.timestamp.map(|t| t.format("%Y-%m-%d").to_string()),
+        };
+        match points.last_mut() {
+            Some(last)
+                if granularity == BalanceGranularity::Day
+                    && date.is_some()
+                    && last.date == date =>
+            {
+                last.block_height = movement.height;
+                last.balance = balance;
+                last.received = last.received.saturating_add(movement.credit);
+                last.spent = last.spent.saturating_add(movement.debit);
+            }
+            _ => points.push(BalancePointDto {
+                block_height: movement.height,
+                date,
+                balance,
+                received: movement.credit,
+                spent: movement.debit,
+            }),
+        }
+    }
+
+    Ok(points)
+}
+
+/// Emits the current balance, then again each time any of its values change.
+///
+/// The stream ends when the sink is closed or the balance can no longer be read.
+#[frb]
+pub async fn watch_balance(
+    sink: StreamSink<AccountBalanceDto>,
+    wallet_name: Option<String>,
+    account_id: Option<i64>,
+) -> Result<()> {
+    let mut changes = BALANCE_CHANGES.subscribe();
+    let mut last_sent: Option<AccountBalanceDto> = None;
+
+    loop {
+        changes.mark_unchanged();
+        let balance = get_balance(wallet_name.clone(), account_id)?;
+        if last_sent.as_ref() != Some(&balance) {
+            if sink.add(balance.clone()).is_err() {
+                return Ok(());
+            }
+            last_sent = Some(balance);
+        }
+
+        tokio::select! {
+            _ = changes.changed() => {}
+            _ = tokio::time::sleep(BALANCE_RECHECK_INTERVAL) => {}
+        }
+    }
+}
+
+/// Wakes balance watchers; they re-read the balance and only emit if it differs.
+pub(crate) fn notify_balance_changed() {
+    BALANCE_CHANGES.send_modify(|generation| *generation = generation.wrapping_add(1));
+}
+
+/// Every outgoing transaction of the account that is not yet past the confirmation window.
+///
+/// A send that stays unconfirmed can end up behind any number of newer transactions, so the
+/// whole history is read rather than only its most recent page.
+fn load_pending_outgoing(
+    conn: &Connection,
+    account_id: i64,
+    confirmation_window: u64,
+) -> Result<Vec<DisplayedTransaction>> {
+    let mut pending = Vec::new();
+    let mut offset = 0;
+    loop {
+        let page =
+            get_displayed_transactions_paginated(conn, account_id, TRANSACTION_PAGE_SIZE, offset)?;
+        let page_len = page.len() as i64;
+        pending.extend(
+            page.into_iter()
+                .filter(|transaction| is_pending_outgoing(transaction, confirmation_window)),
+        );
+        if page_len < TRANSACTION_PAGE_SIZE {
+            return Ok(pending);
+        }
+        offset += page_len;
+    }
+}
+
+fn is_pending_outgoing(transaction: &DisplayedTransaction, confirmation_window: u64) -> bool {
+    let failed = matches!(
+        transaction.status,
+        TransactionDisplayStatus::Rejected | TransactionDisplayStatus::Cancelled
+    );
+    matches!(transaction.direction, TransactionDirection::Outgoing)
+        && !failed
+        && transaction.blockchain.confirmations < confirmation_window
+}
@@ -0,0 +1,201 @@
+use crate::api::address::{parse_address, AddressError};
+use crate::api::db::get_db_connection;
+use crate::api::transactions::DisplayedTransactionDto;
+use anyhow::{bail, Context, Result};
+use flutter_rust_bridge::frb;
+use r2d2_sqlite::rusqlite::{self, params, Connection, OptionalExtension, Row};
+use std::collections::HashMap;
+use tari_common_types::tari_address::TariAddress;
+use tari_utilities::hex::Hex;
+
+const CONTACT_COLUMNS: &str = "id, name, address, notes, is_favourite, created_at";
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct ContactDto {
+    pub id: i64,
+    pub name: String,
+    pub address: String,
+    pub address_emoji: String,
+    pub notes: Option<String>,
+    pub is_favourite: bool,
+    pub created_at: String,
+}
+
+#[frb]
+pub fn add_contact(
+    name: String,
+    address: String,
+    notes: Option<String>,
+    is_favourite: bool,
+) -> Result<ContactDto> {
+    let name = validate_contact_name(&name)?;
+    let address = parse_address(&address)?;
+
+    let conn = get_db_connection()?;
+    conn.execute(
+        "INSERT INTO cw_contacts (name, address, notes, is_favourite) VALUES (?1, ?2, ?3, ?4)",
+        params![name, address.to_base58(), notes, is_favourite],
+    )
+    .with_context(|| format!("Failed to add contact '{}'", name))?;
+
+    get_contact(&conn, conn.last_insert_rowid())
+}
+
+#[frb]
+pub fn update_contact(
+    id: i64,
+    name: String,
+    address: String,
+    notes: Option<String>,
+    is_favourite: bool,
+) -> Result<ContactDto> {
+    let name = validate_contact_name(&name)?;
+    let address = parse_address(&address)?;
+
+    let conn = get_db_connection()?;
+    let updated = conn
+        .execute(
+            "UPDATE cw_contacts
+             SET name = ?2, address = ?3, notes = ?4, is_favourite = ?5,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?1",
+            params![id, name, address.to_base58(), notes, is_favourite],
+        )
+        .with_context(|| format!("Failed to update contact '{}'", name))?;
+    if updated == 0 {
+        bail!("Contact {} not found", id);
+    }
+
+    get_contact(&conn, id)
+}
+
+#[frb]
+pub fn delete_contact(id: i64) -> Result<()> {
+    let conn = get_db_connection()?;
+    conn.execute("DELETE FROM cw_contacts WHERE id = ?1", params![id])
+        .context("Failed to delete contact")?;
+
+    Ok(())
+}
+
+/// Lists contacts with favourites first, then by name.
+#[frb]
+pub fn list_contacts() -> Result<Vec<ContactDto>> {
+    let conn = get_db_connection()?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM cw_contacts ORDER BY is_favourite DESC, name COLLATE NOCASE",
+        CONTACT_COLUMNS
+    ))?;
+    let contacts = stmt
+        .query_map([], contact_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("Failed to list contacts")?;
+
+    Ok(contacts)
+}
+
+#[frb]
+pub fn find_contact_by_name(name: String) -> Result<Option<ContactDto>> {
+    let conn = get_db_connection()?;
+    contact_by_name(&conn, name.trim())
+}
+
+/// Resolves `input` as an address, falling back to the address of the contact with that name.
+pub(crate) fn resolve_recipient(input: &str) -> Result<TariAddress, AddressError> {
+    parse_address(input).or_else(|e| {
+        let contact = get_db_connection()
+            .ok()
+            .and_then(|conn| contact_by_name(&conn, input.trim()).ok().flatten());
+        match contact {
+            Some(contact) => parse_address(&contact.address),
+            None => Err(e),
+        }
+    })
+}
+
+/// Fills in the contact name as the counterparty label wherever the counterparty is a contact.
+///
+/// Contacts are matched on their spend key, so integrated addresses of a contact match too.
+pub(crate) fn apply_contact_labels(
+    conn: &Connection,
+    transactions: &mut [DisplayedTransactionDto],
+) -> Result<()> {
+    if transactions.iter().all(|t| t.counterparty.is_none()) {
+        return Ok(());
+    }
+
+    let mut stmt = conn.prepare("SELECT name, address FROM cw_contacts")?;
+    let labels: HashMap<String, String> = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .filter_map(|r| r.ok())
+        .filter_map(|(name, address)| contact_key(&address).map(|key| (key, name)))
+        .collect();
+
+    for counterparty in transactions
+        .iter_mut()
+        .filter_map(|t| t.counterparty.as_mut())
+        .filter(|c| c.label.is_none())
+    {
+        if let Some(name) = contact_key(&counterparty.address).and_then(|key| labels.get(&key)) {
+            counterparty.label = Some(name.clone());
+        }
+    }
+
+    Ok(())
+}
+
+fn contact_key(address: &str) -> Option<String> {
+    parse_address(address)
+        .ok()
+        .map(|a| format!("{}:{}", a.network(), a.public_spend_key().to_hex()))
+}
+
+fn validate_contact_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("Contact name must not be empty");
+    }
+    Ok(name.to_string())
+}
+
+fn get_contact(conn: &Connection, id: i64) -> Result<ContactDto> {
+    conn.query_row(
+        &format!("SELECT {} FROM cw_contacts WHERE id = ?1", CONTACT_COLUMNS),
+        params![id],
+        contact_from_row,
+    )
+    .with_context(|| format!("Contact {} not found", id))
+}
+
+fn contact_by_name(conn: &Connection, name: &str) -> Result<Option<ContactDto>> {
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM cw_contacts WHERE name = ?1 COLLATE NOCASE",
+            CONTACT_COLUMNS
+        ),
+        params![name],
+        contact_from_row,
+    )
+    .optional()
+    .context("Failed to read contact")
+}
+
+fn contact_from_row(row: &Row) -> rusqlite::Result<ContactDto> {
+    let address: String = row.get(2)?;
+    let address_emoji = TariAddress::from_base58(&address)
+        .map(|a| a.to_emoji_string())
+        .unwrap_or_default();
+
+    Ok(ContactDto {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        address,
+        address_emoji,
+        notes: row.get(3)?,
+        is_favourite: row.get(4)?,
+        created_at: row.get(5)?,
+    })
+}
@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use flutter_rust_bridge::frb;
 use r2d2::{Pool, PooledConnection};
+use r2d2_sqlite::rusqlite::{params, Connection};
 use r2d2_sqlite::SqliteConnectionManager;
 use std::sync::RwLock;
 
@@ -14,11 +15,88 @@ struct DatabaseState {
 
 static DB_STATE: RwLock<Option<DatabaseState>> = RwLock::new(None);
 
+// Tables owned by this crate live next to the minotari schema, so they are prefixed with `cw_`
+// and versioned separately from the upstream migrations.
+const MIGRATIONS: &[&str] = &[
+    // 1: wallet metadata
+    "CREATE TABLE cw_wallets (
+        wallet_name TEXT PRIMARY KEY NOT NULL,
+        network TEXT NOT NULL,
+        birthday INTEGER NOT NULL,
+        spend_public_key_hex TEXT NOT NULL,
+        is_backed_up INTEGER NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );",
+    // 2: scan start height derived from the seed birthday
+    "ALTER TABLE cw_wallets ADD COLUMN scan_start_height INTEGER NOT NULL DEFAULT 0;",
+    // 3: wallets imported from a view key cannot sign
+    "ALTER TABLE cw_wallets ADD COLUMN is_watch_only INTEGER NOT NULL DEFAULT 0;",
+    // 4: seeds enciphered under a user PIN
+    "CREATE TABLE cw_seed_vault (
+        wallet_name TEXT PRIMARY KEY NOT NULL,
+        enciphered_seed BLOB NOT NULL,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );",
+    // 5: addresses with an embedded payment id
+    "CREATE TABLE cw_integrated_addresses (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        wallet_name TEXT NOT NULL,
+        account_id INTEGER NOT NULL,
+        payment_id TEXT NOT NULL,
+        address TEXT NOT NULL,
+        label TEXT,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        UNIQUE (wallet_name, payment_id)
+    );",
+    // 6: address book
+    "CREATE TABLE cw_contacts (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+        address TEXT NOT NULL,
+        notes TEXT,
+        is_favourite INTEGER NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );",
+    // 7: per-network connection overrides
+    "CREATE TABLE cw_network_profiles (
+        network TEXT PRIMARY KEY NOT NULL,
+        base_url TEXT,
+        batch_size INTEGER,
+        confirmation_window INTEGER
+    );",
+    // 8: user-defined connection profiles, at most one active per network
+    "CREATE TABLE cw_custom_network_profiles (
+        name TEXT PRIMARY KEY NOT NULL COLLATE NOCASE,
+        network TEXT NOT NULL,
+        base_url TEXT NOT NULL,
+        batch_size INTEGER,
+        confirmation_window INTEGER,
+        is_active INTEGER NOT NULL DEFAULT 0
+    );
+    CREATE UNIQUE INDEX cw_custom_network_profiles_active
+        ON cw_custom_network_profiles (network) WHERE is_active = 1;",
+    // 9: fallback base node endpoints
+    "CREATE TABLE cw_node_endpoints (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        network TEXT NOT NULL,
+        base_url TEXT NOT NULL,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        UNIQUE(network, base_url)
+    );",
+];
+
 #[frb]
 pub fn initialize_database(path: String) -> Result<()> {
     println!("initializing database {}", path);
 
     let pool = init_db(&path).context("Failed to create database pool")?;
+    {
+        let mut conn = pool
+            .get()
+            .context("Failed to retrieve connection from pool")?;
+        run_migrations(&mut conn).context("Failed to migrate wallet metadata")?;
+    }
 
     let mut guard = DB_STATE
         .write()
@@ -72,3 +150,28 @@ pub(crate) fn get_db_pool() -> Result<Pool<SqliteConnectionManager>> {
 
     Ok(state.pool.clone())
 }
+
+fn run_migrations(conn: &mut Connection) -> Result<()> {
+    let tx = conn.transaction()?;
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS cw_schema_version (version INTEGER NOT NULL)",
+        [],
+    )?;
+    let current: Option<i64> =
+        tx.query_row("SELECT MAX(version) FROM cw_schema_version", [], |row| {
+            row.get(0)
+        })?;
+    let current = current.unwrap_or(0) as usize;
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(current) {
+        tx.execute_batch(migration)
+            .with_context(|| format!("Migration {} failed", index + 1))?;
+        tx.execute(
+            "INSERT INTO cw_schema_version (version) VALUES (?1)",
+            params![index as i64 + 1],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok(())
+}
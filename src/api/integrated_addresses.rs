@@ -0,0 +1,223 @@
+use crate::api::accounts::resolve_account;
+use crate::api::db::get_db_connection;
+use crate::api::network::resolve_wallet_network;
+use crate::api::outputs::load_received_payment_ids;
+use crate::api::transactions::DisplayedTransactionDto;
+use crate::api::wallet::DEFAULT_WALLET_NAME;
+use anyhow::{bail, Context, Result};
+use flutter_rust_bridge::frb;
+use minotari_wallet::transactions::TransactionDirection;
+use r2d2_sqlite::rusqlite::{self, params, Connection, OptionalExtension, Row};
+use std::collections::HashMap;
+use tari_common_types::tari_address::{TariAddress, TariAddressFeatures};
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct AddressFeaturesDto {
+    pub one_sided: bool,
+    pub interactive: bool,
+}
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct IntegratedAddressDto {
+    pub wallet_name: String,
+    pub account_id: i64,
+    pub payment_id: String,
+    pub address: String,
+    pub address_emoji: String,
+    pub label: Option<String>,
+    pub created_at: String,
+}
+
+/// Creates and stores an address with `payment_id` embedded, so deposits to it can be traced back
+/// to whoever it was handed out to.
+#[frb]
+pub fn create_integrated_address(
+    wallet_name: Option<String>,
+    account_id: Option<i64>,
+    passphrase: Option<String>,
+    network: Option<String>,
+    payment_id: String,
+    features: Option<AddressFeaturesDto>,
+    label: Option<String>,
+) -> Result<IntegratedAddressDto> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    if payment_id.is_empty() {
+        bail!("Payment id must not be empty");
+    }
+    let mut conn = get_db_connection()?;
+    let account = resolve_account(&mut conn, Some(&wallet_name), account_id)?;
+    let network = resolve_wallet_network(&conn, &wallet_name, network)?;
+    let base_address = account.get_address(network, passphrase.as_deref().unwrap_or(""))?;
+
+    if find_by_payment_id(&conn, &wallet_name, &payment_id)?.is_some() {
+        bail!(
+            "Payment id '{}' is already used by another address of this wallet",
+            payment_id
+        );
+    }
+
+    let address = TariAddress::new_dual_address(
+        base_address
+            .public_view_key()
+            .context("Wallet address has no view key")?
+            .clone(),
+        base_address.public_spend_key().clone(),
+        network,
+        to_address_features(features)? | TariAddressFeatures::PAYMENT_ID,
+        Some(payment_id.as_bytes().to_vec()),
+    )
+    .context("Failed to generate integrated address")?;
+
+    conn.execute(
+        "INSERT INTO cw_integrated_addresses (wallet_name, account_id, payment_id, address, label)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            wallet_name,
+            account.id,
+            payment_id,
+            address.to_base58(),
+            label
+        ],
+    )
+    .context("Failed to store integrated address")?;
+
+    find_by_payment_id(&conn, &wallet_name, &payment_id)?
+        .context("Integrated address was not stored")
+}
+
+#[frb]
+pub fn list_integrated_addresses(wallet_name: Option<String>) -> Result<Vec<IntegratedAddressDto>> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let conn = get_db_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT wallet_name, account_id, payment_id, address, label, created_at
+         FROM cw_integrated_addresses WHERE wallet_name = ?1 ORDER BY id",
+    )?;
+    let addresses = stmt
+        .query_map(params![wallet_name], integrated_address_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("Failed to list integrated addresses")?;
+
+    Ok(addresses)
+}
+
+/// Looks up the integrated address a received payment id belongs to, if any.
+#[frb]
+pub fn find_integrated_address(
+    wallet_name: Option<String>,
+    payment_id: String,
+) -> Result<Option<IntegratedAddressDto>> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let conn = get_db_connection()?;
+    find_by_payment_id(&conn, &wallet_name, &payment_id)
+}
+
+#[frb]
+pub fn delete_integrated_address(wallet_name: Option<String>, payment_id: String) -> Result<()> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let conn = get_db_connection()?;
+    conn.execute(
+        "DELETE FROM cw_integrated_addresses WHERE wallet_name = ?1 AND payment_id = ?2",
+        params![wallet_name, payment_id],
+    )
+    .context("Failed to delete integrated address")?;
+
+    Ok(())
+}
+
+/// Fills in the integrated address each incoming payment of the account was sent to, matched by
+/// the payment id data carried in the outputs it received.
+pub(crate) fn apply_integrated_addresses(
+    conn: &Connection,
+    account_id: i64,
+    transactions: &mut [DisplayedTransactionDto],
+) -> Result<()> {
+    let incoming = TransactionDirection::Incoming.as_label();
+    if !transactions
+        .iter()
+        .any(|t| t.direction == incoming && t.integrated_address.is_none())
+    {
+        return Ok(());
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT wallet_name, account_id, payment_id, address, label, created_at
+         FROM cw_integrated_addresses WHERE account_id = ?1",
+    )?;
+    let addresses: HashMap<Vec<u8>, IntegratedAddressDto> = stmt
+        .query_map(params![account_id], integrated_address_from_row)?
+        .filter_map(|r| r.ok())
+        .map(|address| (address.payment_id.as_bytes().to_vec(), address))
+        .collect();
+    if addresses.is_empty() {
+        return Ok(());
+    }
+
+    let received: HashMap<String, IntegratedAddressDto> =
+        load_received_payment_ids(conn, account_id)?
+            .into_iter()
+            .filter_map(|(tx_id, payment_id)| Some((tx_id, addresses.get(&payment_id)?.clone())))
+            .collect();
+
+    for transaction in transactions
+        .iter_mut()
+        .filter(|t| t.direction == incoming && t.integrated_address.is_none())
+    {
+        transaction.integrated_address = received.get(&transaction.id).cloned();
+    }
+
+    Ok(())
+}
+
+fn to_address_features(features: Option<AddressFeaturesDto>) -> Result<TariAddressFeatures> {
+    let Some(features) = features else {
+        return Ok(TariAddressFeatures::create_one_sided_only());
+    };
+
+    let mut result = TariAddressFeatures::empty();
+    if features.one_sided {
+        result |= TariAddressFeatures::ONE_SIDED;
+    }
+    if features.interactive {
+        result |= TariAddressFeatures::INTERACTIVE;
+    }
+    if result.is_empty() {
+        bail!("An address must support one-sided or interactive payments");
+    }
+
+    Ok(result)
+}
+
+fn find_by_payment_id(
+    conn: &Connection,
+    wallet_name: &str,
+    payment_id: &str,
+) -> Result<Option<IntegratedAddressDto>> {
+    conn.query_row(
+        "SELECT wallet_name, account_id, payment_id, address, label, created_at
+         FROM cw_integrated_addresses WHERE wallet_name = ?1 AND payment_id = ?2",
+        params![wallet_name, payment_id],
+        integrated_address_from_row,
+    )
+    .optional()
+    .context("Failed to read integrated address")
+}
+
+fn integrated_address_from_row(row: &Row) -> rusqlite::Result<IntegratedAddressDto> {
+    let address: String = row.get(3)?;
+    let address_emoji = TariAddress::from_base58(&address)
+        .map(|a| a.to_emoji_string())
+        .unwrap_or_default();
+
+    Ok(IntegratedAddressDto {
+        wallet_name: row.get(0)?,
+        account_id: row.get(1)?,
+        payment_id: row.get(2)?,
+        address,
+        address_emoji,
+        label: row.get(4)?,
+        created_at: row.get(5)?,
+    })
+}
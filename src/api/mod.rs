@@ -1,9 +1,20 @@
+pub mod accounts;
 pub mod address;
 pub mod balance;
+pub mod contacts;
 pub mod db;
+pub mod integrated_addresses;
 pub mod network;
+pub mod network_profiles;
+pub mod node;
+pub mod offline_signing;
+pub mod outputs;
+pub mod payment_uri;
+pub mod portfolio;
+pub mod proxy;
 pub mod scanner;
 pub mod seeds;
 pub mod send_transaction;
 pub mod transactions;
+pub mod vault;
 pub mod wallet;
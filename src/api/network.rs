@@ -1,6 +1,42 @@
+use crate::api::wallet::find_wallet_record;
 use anyhow::{Context, Result};
+use r2d2_sqlite::rusqlite::{self, Connection};
 use std::str::FromStr;
 use tari_common::configuration::Network;
+use tari_common_types::seeds::cipher_seed::BIRTHDAY_GENESIS_FROM_UNIX_EPOCH;
+use tari_common_types::tari_address::TariAddress;
+use tari_transaction_components::consensus::ConsensusConstantsBuilder;
+use tari_transaction_components::tari_proof_of_work::PowAlgorithm;
+use thiserror::Error;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+// Only used if the consensus constants define no proof-of-work targets.
+const DEFAULT_BLOCK_INTERVAL_SECONDS: u64 = 120;
+// Block times drift from the target, so start scanning a little before the estimated height.
+const BIRTHDAY_SAFETY_MARGIN_DAYS: u64 = 2;
+
+#[derive(Error, Debug)]
+pub enum NetworkError {
+    #[error("Wallet '{wallet_name}' is on {wallet_network}, not {requested}")]
+    WalletMismatch {
+        wallet_name: String,
+        wallet_network: String,
+        requested: String,
+    },
+
+    #[error("Address is for {address_network}, but wallet '{wallet_name}' is on {wallet_network}")]
+    AddressMismatch {
+        wallet_name: String,
+        wallet_network: String,
+        address_network: String,
+    },
+
+    #[error("No network given and no wallet to take it from")]
+    Unspecified,
+
+    #[error("No network given and stored wallets are on several: {0}")]
+    Ambiguous(String),
+}
 
 pub(crate) fn parse_network(network: Option<String>) -> Result<Network> {
     network
@@ -8,3 +44,104 @@ pub(crate) fn parse_network(network: Option<String>) -> Result<Network> {
         .map_or_else(|| Ok(Network::MainNet), Network::from_str)
         .context("failed to parse network")
 }
+
+/// The requested network, or else the one every stored wallet is on.
+///
+/// For operations that aren't tied to a wallet, where guessing a network would silently talk to
+/// the wrong chain.
+pub(crate) fn resolve_default_network(
+    conn: &Connection,
+    requested: Option<String>,
+) -> Result<Network> {
+    if requested.is_some() {
+        return parse_network(requested);
+    }
+
+    let mut stmt = conn.prepare("SELECT DISTINCT network FROM cw_wallets ORDER BY network")?;
+    let networks = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()
+        .context("Failed to read wallet networks")?;
+    match networks.as_slice() {
+        [network] => parse_network(Some(network.clone())),
+        [] => Err(NetworkError::Unspecified.into()),
+        _ => Err(NetworkError::Ambiguous(networks.join(", ")).into()),
+    }
+}
+
+/// The network the wallet was created on. An explicitly requested network must agree with it.
+///
+/// Wallets created before networks were recorded fall back to the requested network.
+pub(crate) fn resolve_wallet_network(
+    conn: &Connection,
+    wallet_name: &str,
+    requested: Option<String>,
+) -> Result<Network> {
+    let Some(record) = find_wallet_record(conn, wallet_name)? else {
+        return parse_network(requested);
+    };
+
+    if let Some(requested) = requested {
+        let requested = parse_network(Some(requested))?;
+        if requested != record.network {
+            return Err(NetworkError::WalletMismatch {
+                wallet_name: wallet_name.to_string(),
+                wallet_network: record.network.to_string(),
+                requested: requested.to_string(),
+            }
+            .into());
+        }
+    }
+
+    Ok(record.network)
+}
+
+pub(crate) fn ensure_address_network(
+    wallet_name: &str,
+    wallet_network: Network,
+    address: &TariAddress,
+) -> Result<(), NetworkError> {
+    if address.network() != wallet_network {
+        return Err(NetworkError::AddressMismatch {
+            wallet_name: wallet_name.to_string(),
+            wallet_network: wallet_network.to_string(),
+            address_network: address.network().to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// The chain's expected block interval, combined over every proof-of-work algorithm's target.
+fn target_block_interval(network: Network) -> u64 {
+    let constants = ConsensusConstantsBuilder::new(network).build();
+    let blocks_per_second: f64 = (0..u64::from(u8::MAX))
+        .map_while(|algo| PowAlgorithm::try_from(algo).ok())
+        .map(|algo| constants.pow_target_block_interval(algo))
+        .filter(|&target| target > 0)
+        .map(|target| 1.0 / target as f64)
+        .sum();
+
+    if blocks_per_second > 0.0 {
+        (1.0 / blocks_per_second).round().max(1.0) as u64
+    } else {
+        DEFAULT_BLOCK_INTERVAL_SECONDS
+    }
+}
+
+/// Estimates the first block height that can contain outputs for a seed with the given birthday,
+/// counting blocks from the time of the network's genesis block.
+pub(crate) fn birthday_to_height(birthday: u16, network: Network, genesis_timestamp: u64) -> u64 {
+    let days = u64::from(birthday).saturating_sub(BIRTHDAY_SAFETY_MARGIN_DAYS);
+    let timestamp = BIRTHDAY_GENESIS_FROM_UNIX_EPOCH.saturating_add(days * SECONDS_PER_DAY);
+
+    timestamp.saturating_sub(genesis_timestamp) / target_block_interval(network)
+}
+
+/// The latest birthday whose day starts no later than `timestamp`.
+///
+/// Handing the birthday of a block's timestamp to the scanner makes it start at or before that
+/// block, whatever margin it subtracts on its own.
+pub(crate) fn timestamp_to_birthday(timestamp: u64) -> Result<u16> {
+    let days = timestamp.saturating_sub(BIRTHDAY_GENESIS_FROM_UNIX_EPOCH) / SECONDS_PER_DAY;
+    u16::try_from(days).with_context(|| format!("Block time {} is out of range", timestamp))
+}
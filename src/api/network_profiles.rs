@@ -0,0 +1,295 @@
+use crate::api::db::get_db_connection;
+use crate::api::network::parse_network;
+use crate::api::node::normalize_url;
+use anyhow::{bail, Context, Result};
+use flutter_rust_bridge::frb;
+use r2d2_sqlite::rusqlite::{self, params, Connection, OptionalExtension, Row};
+use tari_common::configuration::Network;
+
+const DEFAULT_BATCH_SIZE: u64 = 100;
+const DEFAULT_CONFIRMATION_WINDOW: u64 = 3;
+
+const KNOWN_NETWORKS: [Network; 4] = [
+    Network::MainNet,
+    Network::NextNet,
+    Network::Esmeralda,
+    Network::LocalNet,
+];
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct NetworkProfileDto {
+    /// The network name for built-in profiles, the user's name for custom ones.
+    pub name: String,
+    pub network: String,
+    /// `None` for networks without a public node when the user has not configured one.
+    pub base_url: Option<String>,
+    pub batch_size: u64,
+    pub confirmation_window: u64,
+    /// Whether any of the values come from a user override.
+    pub is_customized: bool,
+    pub is_custom: bool,
+    /// Whether APIs resolve this network's connection settings from this profile.
+    pub is_active: bool,
+}
+
+const CUSTOM_PROFILE_COLUMNS: &str =
+    "name, network, base_url, batch_size, confirmation_window, is_active";
+
+#[frb]
+pub fn list_network_profiles() -> Result<Vec<NetworkProfileDto>> {
+    let conn = get_db_connection()?;
+    let mut networks: Vec<Network> = KNOWN_NETWORKS.to_vec();
+
+    let mut stmt = conn.prepare("SELECT network FROM cw_network_profiles ORDER BY network")?;
+    for network in stmt.query_map([], |row| row.get::<_, String>(0))? {
+        let network = parse_network(Some(network?))?;
+        if !networks.contains(&network) {
+            networks.push(network);
+        }
+    }
+
+    let mut profiles = networks
+        .into_iter()
+        .map(|network| builtin_profile(&conn, network))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM cw_custom_network_profiles ORDER BY name",
+        CUSTOM_PROFILE_COLUMNS
+    ))?;
+    let custom = stmt
+        .query_map([], custom_profile_from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("Failed to list custom network profiles")?;
+    profiles.extend(custom);
+
+    Ok(profiles)
+}
+
+#[frb]
+pub fn get_network_profile(network: String) -> Result<NetworkProfileDto> {
+    let network = parse_network(Some(network))?;
+    let conn = get_db_connection()?;
+    resolve_network_profile(&conn, network)
+}
+
+/// Stores user overrides for a network; `None` values keep the built-in default.
+#[frb]
+pub fn set_network_profile(
+    network: String,
+    base_url: Option<String>,
+    batch_size: Option<u64>,
+    confirmation_window: Option<u64>,
+) -> Result<NetworkProfileDto> {
+    let network = parse_network(Some(network))?;
+    let base_url = base_url
+        .filter(|url| !url.trim().is_empty())
+        .map(|url| normalize_url(&url))
+        .transpose()?;
+
+    let conn = get_db_connection()?;
+    conn.execute(
+        "INSERT OR REPLACE INTO cw_network_profiles
+            (network, base_url, batch_size, confirmation_window)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![
+            network.to_string(),
+            base_url,
+            batch_size,
+            confirmation_window
+        ],
+    )
+    .context("Failed to store network profile")?;
+
+    builtin_profile(&conn, network)
+}
+
+/// Creates or replaces a named profile for a node of the user's own, e.g. a self-hosted base node.
+///
+/// The profile is used once activated with [`activate_network_profile`].
+#[frb]
+pub fn set_custom_network_profile(
+    name: String,
+    network: String,
+    base_url: String,
+    batch_size: Option<u64>,
+    confirmation_window: Option<u64>,
+) -> Result<NetworkProfileDto> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        bail!("Profile name must not be empty");
+    }
+    if parse_network(Some(name.clone())).is_ok() {
+        bail!("'{}' is reserved for the built-in profile", name);
+    }
+    let network = parse_network(Some(network))?;
+    if base_url.trim().is_empty() {
+        bail!("A custom profile needs a base node URL");
+    }
+    let base_url = normalize_url(&base_url)?;
+
+    let conn = get_db_connection()?;
+    // Moving a profile to another network deactivates it there.
+    conn.execute(
+        "INSERT INTO cw_custom_network_profiles
+            (name, network, base_url, batch_size, confirmation_window)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT (name) DO UPDATE SET
+            is_active = is_active AND network = excluded.network,
+            network = excluded.network,
+            base_url = excluded.base_url,
+            batch_size = excluded.batch_size,
+            confirmation_window = excluded.confirmation_window",
+        params![
+            name,
+            network.to_string(),
+            base_url,
+            batch_size,
+            confirmation_window
+        ],
+    )
+    .context("Failed to store custom network profile")?;
+
+    find_custom_profile(&conn, "name = ?1", &name)?.context("Custom profile was not stored")
+}
+
+/// Makes the custom profile `name` the one used for `network`, or the built-in one when `None`.
+#[frb]
+pub fn activate_network_profile(
+    network: String,
+    name: Option<String>,
+) -> Result<NetworkProfileDto> {
+    let network = parse_network(Some(network))?;
+    let mut conn = get_db_connection()?;
+
+    let tx = conn.transaction()?;
+    tx.execute(
+        "UPDATE cw_custom_network_profiles SET is_active = 0 WHERE network = ?1",
+        params![network.to_string()],
+    )?;
+    if let Some(name) = name {
+        let updated = tx.execute(
+            "UPDATE cw_custom_network_profiles SET is_active = 1
+             WHERE name = ?1 AND network = ?2",
+            params![name.trim(), network.to_string()],
+        )?;
+        if updated == 0 {
+            bail!("No custom profile '{}' for {}", name.trim(), network);
+        }
+    }
+    tx.commit().context("Failed to activate network profile")?;
+
+    resolve_network_profile(&conn, network)
+}
+
+#[frb]
+pub fn delete_custom_network_profile(name: String) -> Result<()> {
+    let conn = get_db_connection()?;
+    conn.execute(
+        "DELETE FROM cw_custom_network_profiles WHERE name = ?1",
+        params![name.trim()],
+    )
+    .context("Failed to delete custom network profile")?;
+
+    Ok(())
+}
+
+#[frb]
+pub fn reset_network_profile(network: String) -> Result<NetworkProfileDto> {
+    let network = parse_network(Some(network))?;
+    let conn = get_db_connection()?;
+    conn.execute(
+        "DELETE FROM cw_network_profiles WHERE network = ?1",
+        params![network.to_string()],
+    )
+    .context("Failed to reset network profile")?;
+
+    builtin_profile(&conn, network)
+}
+
+/// The profile APIs take `network`'s connection settings from: the active custom profile, if any,
+/// otherwise the built-in one with the user's overrides.
+pub(crate) fn resolve_network_profile(
+    conn: &Connection,
+    network: Network,
+) -> Result<NetworkProfileDto> {
+    if let Some(profile) =
+        find_custom_profile(conn, "network = ?1 AND is_active = 1", &network.to_string())?
+    {
+        return Ok(profile);
+    }
+    builtin_profile(conn, network)
+}
+
+fn builtin_profile(conn: &Connection, network: Network) -> Result<NetworkProfileDto> {
+    let overrides: Option<(Option<String>, Option<u64>, Option<u64>)> = conn
+        .query_row(
+            "SELECT base_url, batch_size, confirmation_window
+             FROM cw_network_profiles WHERE network = ?1",
+            params![network.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()
+        .context("Failed to read network profile")?;
+    let (base_url, batch_size, confirmation_window) = overrides.clone().unwrap_or_default();
+    let has_active_custom: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM cw_custom_network_profiles
+                        WHERE network = ?1 AND is_active = 1)",
+        params![network.to_string()],
+        |row| row.get(0),
+    )?;
+
+    Ok(NetworkProfileDto {
+        name: network.to_string(),
+        network: network.to_string(),
+        base_url: base_url.or_else(|| default_base_url(network).map(str::to_string)),
+        batch_size: batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
+        confirmation_window: confirmation_window.unwrap_or(DEFAULT_CONFIRMATION_WINDOW),
+        is_customized: overrides.is_some(),
+        is_custom: false,
+        is_active: !has_active_custom,
+    })
+}
+
+fn find_custom_profile(
+    conn: &Connection,
+    condition: &str,
+    value: &str,
+) -> Result<Option<NetworkProfileDto>> {
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM cw_custom_network_profiles WHERE {}",
+            CUSTOM_PROFILE_COLUMNS, condition
+        ),
+        params![value],
+        custom_profile_from_row,
+    )
+    .optional()
+    .context("Failed to read custom network profile")
+}
+
+fn custom_profile_from_row(row: &Row) -> rusqlite::Result<NetworkProfileDto> {
+    let batch_size: Option<u64> = row.get(3)?;
+    let confirmation_window: Option<u64> = row.get(4)?;
+    Ok(NetworkProfileDto {
+        name: row.get(0)?,
+        network: row.get(1)?,
+        base_url: Some(row.get(2)?),
+        batch_size: batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
+        confirmation_window: confirmation_window.unwrap_or(DEFAULT_CONFIRMATION_WINDOW),
+        is_customized: true,
+        is_custom: true,
+        is_active: row.get(5)?,
+    })
+}
+
+fn default_base_url(network: Network) -> Option<&'static str> {
+    match network {
+        Network::MainNet => Some("https://rpc.tari.com"),
+        Network::NextNet => Some("https://rpc.nextnet.tari.com"),
+        Network::Esmeralda => Some("https://rpc.esmeralda.tari.com"),
+        Network::LocalNet => Some("http://127.0.0.1:9000"),
+        _ => None,
+    }
+}
@@ -0,0 +1,370 @@
+use crate::api::db::get_db_connection;
+use crate::api::network::{parse_network, resolve_default_network};
+use crate::api::network_profiles::resolve_network_profile;
+use crate::api::proxy::{http_client, resolve_proxy, ProxySettingsDto};
+use anyhow::{anyhow, Context, Result};
+use flutter_rust_bridge::frb;
+use r2d2_sqlite::rusqlite::{params, Connection};
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tari_common::configuration::Network;
+
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+const HEALTH_CHECK_ATTEMPTS: u32 = 3;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct NodeStatusDto {
+    pub base_url: String,
+    pub is_reachable: bool,
+    pub is_synced: bool,
+    pub tip_height: Option<u64>,
+    pub latency_ms: Option<u64>,
+    pub version: Option<String>,
+    pub error: Option<String>,
+}
+
+impl NodeStatusDto {
+    fn is_healthy(&self) -> bool {
+        self.is_reachable && self.is_synced
+    }
+}
+
+/// Checks every endpoint configured for `network`, in failover order.
+#[frb]
+pub async fn get_node_status(network: Option<String>) -> Result<Vec<NodeStatusDto>> {
+    let proxy = resolve_proxy(None)?;
+    let candidates = {
+        let conn = get_db_connection()?;
+        let network = resolve_default_network(&conn, network)?;
+        node_candidates(&conn, network, None)?
+    };
+
+    let checks = candidates.into_iter().map(|base_url| {
+        let proxy = proxy.clone();
+        tokio::spawn(async move { check_node(&base_url, proxy.as_ref()).await })
+    });
+
+    let mut statuses = Vec::new();
+    for check in checks {
+        statuses.push(check.await.context("Health check task failed")?);
+    }
+    Ok(statuses)
+}
+
+#[frb]
+pub fn list_node_endpoints(network: String) -> Result<Vec<String>> {
+    let network = parse_network(Some(network))?;
+    let conn = get_db_connection()?;
+    node_candidates(&conn, network, None)
+}
+
+/// Adds a fallback endpoint, tried after the network profile's base URL.
+#[frb]
+pub fn add_node_endpoint(network: String, base_url: String) -> Result<Vec<String>> {
+    let network = parse_network(Some(network))?;
+    let base_url = normalize_url(&base_url)?;
+
+    let conn = get_db_connection()?;
+    conn.execute(
+        "INSERT OR IGNORE INTO cw_node_endpoints (network, base_url) VALUES (?1, ?2)",
+        params![network.to_string(), base_url],
+    )
+    .context("Failed to store node endpoint")?;
+
+    node_candidates(&conn, network, None)
+}
+
+#[frb]
+pub fn remove_node_endpoint(network: String, base_url: String) -> Result<Vec<String>> {
+    let network = parse_network(Some(network))?;
+    let base_url = normalize_url(&base_url)?;
+
+    let conn = get_db_connection()?;
+    conn.execute(
+        "DELETE FROM cw_node_endpoints WHERE network = ?1 AND base_url = ?2",
+        params![network.to_string(), base_url],
+    )
+    .context("Failed to remove node endpoint")?;
+
+    node_candidates(&conn, network, None)
+}
+
+/// Endpoints in the order they should be tried: an explicit URL, the profile URL, then fallbacks.
+pub(crate) fn node_candidates(
+    conn: &Connection,
+    network: Network,
+    explicit: Option<String>,
+) -> Result<Vec<String>> {
+    let mut candidates = Vec::new();
+    let mut push = |url: String| {
+        if !candidates.contains(&url) {
+            candidates.push(url);
+        }
+    };
+
+    if let Some(url) = explicit {
+        push(normalize_url(&url)?);
+    }
+    if let Some(url) = resolve_network_profile(conn, network)?.base_url {
+        push(url);
+    }
+
+    let mut stmt =
+        conn.prepare("SELECT base_url FROM cw_node_endpoints WHERE network = ?1 ORDER BY id")?;
+    for url in stmt.query_map(params![network.to_string()], |row| row.get::<_, String>(0))? {
+        push(url?);
+    }
+
+    if candidates.is_empty() {
+        return Err(anyhow!("No base node configured for {}", network));
+    }
+    Ok(candidates)
+}
+
+/// Returns the first healthy endpoint, retrying the whole list with exponential backoff.
+///
+/// `avoid` is tried last, so a node that just failed is only reused when nothing else is up.
+pub(crate) async fn select_healthy_endpoint(
+    candidates: &[String],
+    avoid: Option<&str>,
+    proxy: Option<&ProxySettingsDto>,
+) -> Result<String> {
+    let mut ordered: Vec<&String> = candidates
+        .iter()
+        .filter(|url| Some(url.as_str()) != avoid)
+        .collect();
+    ordered.extend(candidates.iter().filter(|url| Some(url.as_str()) == avoid));
+
+    let mut last_error = None;
+    for attempt in 0..HEALTH_CHECK_ATTEMPTS {
+        if attempt > 0 {
+            tokio::time::sleep(backoff_delay(attempt - 1)).await;
+        }
+        for url in &ordered {
+            let status = check_node(url, proxy).await;
+            if status.is_healthy() {
+                return Ok(status.base_url);
+            }
+            last_error = Some(format!(
+                "{}: {}",
+                url,
+                status
+                    .error
+                    .unwrap_or_else(|| "node is not synced".to_string())
+            ));
+        }
+    }
+
+    Err(anyhow!(
+        "No healthy base node available ({})",
+        last_error.unwrap_or_else(|| "no endpoints configured".to_string())
+    ))
+}
+
+/// Seconds since the Unix epoch at which the block at `height` was built, read from the first
+/// endpoint that serves its header.
+pub(crate) async fn block_timestamp(
+    candidates: &[String],
+    height: u64,
+    proxy: Option<&ProxySettingsDto>,
+) -> Result<u64> {
+    let client = http_client(proxy, Some(HEALTH_CHECK_TIMEOUT))?;
+    let mut last_error = None;
+    for url in candidates {
+        let header_url = format!("{}/get_header_by_height?height={}", url, height);
+        match fetch_json(&client, &header_url).await {
+            Ok(header) => {
+                return header
+                    .get("timestamp")
+                    .and_then(Value::as_u64)
+                    .with_context(|| format!("{} returned a header without a timestamp", url))
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(last_error
+        .unwrap_or_else(|| anyhow!("No base node configured"))
+        .context(format!("Failed to read the header of block {}", height)))
+}
+
+/// A single health check, used to tell a failed node apart from other errors.
+pub(crate) async fn is_node_healthy(base_url: &str, proxy: Option<&ProxySettingsDto>) -> bool {
+    check_node(base_url, proxy).await.is_healthy()
+}
+
+pub(crate) fn backoff_delay(attempt: u32) -> Duration {
+    INITIAL_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF)
+}
+
+async fn check_node(base_url: &str, proxy: Option<&ProxySettingsDto>) -> NodeStatusDto {
+    let mut status = NodeStatusDto {
+        base_url: base_url.to_string(),
+        is_reachable: false,
+        is_synced: false,
+        tip_height: None,
+        latency_ms: None,
+        version: None,
+        error: None,
+    };
+
+    let client = match http_client(proxy, Some(HEALTH_CHECK_TIMEOUT)) {
+        Ok(client) => client,
+        Err(e) => {
+            status.error = Some(e.to_string());
+            return status;
+        }
+    };
+
+    let started = Instant::now();
+    let tip_info = match fetch_json(&client, &format!("{}/get_tip_info", base_url)).await {
+        Ok(tip_info) => tip_info,
+        Err(e) => {
+            status.error = Some(e.to_string());
+            return status;
+        }
+    };
+    status.latency_ms = Some(started.elapsed().as_millis() as u64);
+    status.is_reachable = true;
+    status.tip_height = tip_info
+        .pointer("/metadata/best_block_height")
+        .and_then(Value::as_u64);
+    status.is_synced = tip_info
+        .get("is_synced")
+        .and_then(Value::as_bool)
+        .unwrap_or(status.tip_height.is_some());
+
+    // Older nodes don't expose a version; that alone doesn't make them unhealthy.
+    status.version = fetch_json(&client, &format!("{}/version", base_url))
+        .await
+        .ok()
+        .and_then(|v| match v {
+            Value::String(version) => Some(version),
+            other => other
+                .get("version")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        });
+
+    status
+}
+
+async fn fetch_json(client: &reqwest::Client, url: &str) -> Result<Value> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach {}", url))?
+        .error_for_status()?;
+    response
+        .json::<Value>()
+        .await
+        .with_context(|| format!("Unexpected response from {}", url))
+}
+
+pub(crate) fn normalize_url(url: &str) -> Result<String> {
+    let url = url.trim().trim_end_matches('/');
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err(anyhow!("Base node URL must start with http:// or https://"));
+    }
+    Ok(url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{closed_port, stand_in_node};
+
+    const SYNCED: &str = r#"{"metadata":{"best_block_height":1234},"is_synced":true}"#;
+    const SYNCING: &str = r#"{"metadata":{"best_block_height":12},"is_synced":false}"#;
+
+    #[tokio::test]
+    async fn check_node_reads_tip_and_version() {
+        let url = stand_in_node(SYNCED, Some(r#"{"version":"5.2.0"}"#)).await;
+
+        let status = check_node(&url, None).await;
+
+        assert!(status.is_reachable);
+        assert!(status.is_synced);
+        assert_eq!(status.tip_height, Some(1234));
+        assert_eq!(status.version.as_deref(), Some("5.2.0"));
+        assert!(status.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn check_node_tolerates_missing_version() {
+        let url = stand_in_node(SYNCED, None).await;
+
+        let status = check_node(&url, None).await;
+
+        assert!(status.is_healthy());
+        assert!(status.version.is_none());
+    }
+
+    #[tokio::test]
+    async fn check_node_reports_unreachable_node() {
+        let url = closed_port().await;
+
+        let status = check_node(&url, None).await;
+
+        assert!(!status.is_reachable);
+        assert!(!status.is_healthy());
+        assert!(status.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn select_skips_unreachable_and_syncing_nodes() {
+        let down = closed_port().await;
+        let syncing = stand_in_node(SYNCING, None).await;
+        let healthy = stand_in_node(SYNCED, None).await;
+
+        let selected = select_healthy_endpoint(&[down, syncing, healthy.clone()], None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(selected, healthy);
+    }
+
+    #[tokio::test]
+    async fn select_tries_the_avoided_node_last() {
+        let first = stand_in_node(SYNCED, None).await;
+        let second = stand_in_node(SYNCED, None).await;
+        let candidates = [first.clone(), second.clone()];
+
+        assert_eq!(
+            select_healthy_endpoint(&candidates, Some(&first), None)
+                .await
+                .unwrap(),
+            second
+        );
+        let down = closed_port().await;
+        assert_eq!(
+            select_healthy_endpoint(&[first.clone(), down.clone()], Some(&first), None)
+                .await
+                .unwrap(),
+            first
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_node_is_told_apart_from_a_healthy_one() {
+        let healthy = stand_in_node(SYNCED, None).await;
+        let down = closed_port().await;
+
+        assert!(is_node_healthy(&healthy, None).await);
+        assert!(!is_node_healthy(&down, None).await);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        assert_eq!(backoff_delay(0), INITIAL_BACKOFF);
+        assert_eq!(backoff_delay(1), INITIAL_BACKOFF * 2);
+        assert_eq!(backoff_delay(2), INITIAL_BACKOFF * 4);
+        assert_eq!(backoff_delay(20), MAX_BACKOFF);
+    }
+}
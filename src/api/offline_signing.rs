@@ -0,0 +1,370 @@
+use crate::api::balance::notify_balance_changed;
+use crate::api::db::get_db_connection;
+use crate::api::network::{parse_network, resolve_wallet_network};
+use crate::api::network_profiles::resolve_network_profile;
+use crate::api::node::{node_candidates, select_healthy_endpoint};
+use crate::api::proxy::{proxied_endpoint, resolve_proxy, ProxySettingsDto};
+use crate::api::send_transaction::{
+    build_unsigned_transaction, create_transaction_sender, derive_key_manager, validate_inputs,
+    SendTransactionDetails, TransactionError,
+};
+use crate::api::transactions::DisplayedTransactionDto;
+use anyhow::{anyhow, bail, Context, Result};
+use flutter_rust_bridge::frb;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tari_transaction_components::consensus::ConsensusConstantsBuilder;
+use tari_transaction_components::offline_signing::models::{
+    PrepareOneSidedTransactionForSigningResult, SignedOneSidedTransactionResult,
+};
+use tari_transaction_components::offline_signing::sign_locked_transaction;
+use tari_utilities::hex::{from_hex, to_hex};
+
+const PAYLOAD_VERSION: u32 = 1;
+// Uppercase hex, digits and `:/` all fit the QR alphanumeric mode, which packs the densest codes.
+const QR_CHUNK_PREFIX: &str = "TARITX";
+const QR_CHUNK_DATA_SIZE: usize = 1000;
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct OfflineTransactionPayloadDto {
+    pub json: String,
+    pub qr_chunks: Vec<String>,
+}
+
+#[frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OfflineRecipientDto {
+    pub address: String,
+    pub amount: u64,
+    pub payment_id: String,
+}
+
+/// What an exported transaction pays out, for review on the signing device.
+#[frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OfflineTransactionSummaryDto {
+    pub network: String,
+    pub wallet_name: String,
+    pub recipients: Vec<OfflineRecipientDto>,
+    pub fee: u64,
+    /// Recipient amounts plus the fee.
+    pub total: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PayloadKind {
+    Unsigned,
+    Signed,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OfflinePayload<T> {
+    version: u32,
+    kind: PayloadKind,
+    network: String,
+    wallet_name: String,
+    transaction: T,
+}
+
+/// Builds and locks a transaction on the watch-only device and exports it for an offline signer.
+///
+/// `seed_words` in `details` is ignored; nothing on this path needs the seed.
+#[frb]
+pub fn export_unsigned_transaction(
+    details: SendTransactionDetails,
+) -> Result<OfflineTransactionPayloadDto> {
+    let validated = validate_inputs(&details)?;
+    let mut sender = create_transaction_sender(
+        details.passphrase.as_deref(),
+        &validated.wallet_name,
+        validated.network,
+        validated.confirmations,
+    )?;
+
+    let unsigned_tx = build_unsigned_transaction(
+        &mut sender,
+        validated.recipient_address,
+        validated.amount,
+        details.payment_id,
+    )?;
+
+    encode_payload(&OfflinePayload {
+        version: PAYLOAD_VERSION,
+        kind: PayloadKind::Unsigned,
+        network: validated.network.to_string(),
+        wallet_name: validated.wallet_name,
+        transaction: unsigned_tx,
+    })
+}
+
+/// Decodes an exported transaction so its recipients, amounts and fee can be shown before signing.
+#[frb]
+pub fn inspect_unsigned_transaction(payload_json: String) -> Result<OfflineTransactionSummaryDto> {
+    let payload: OfflinePayload<PrepareOneSidedTransactionForSigningResult> =
+        decode_payload(&payload_json, PayloadKind::Unsigned)?;
+    summarize_payload(&payload)
+}
+
+/// Signs an exported transaction on the offline, seed-holding device.
+///
+/// `confirmed` is the summary from [`inspect_unsigned_transaction`] the user approved; the payload
+/// is only signed if it still pays out exactly that.
+#[frb]
+pub fn sign_offline_transaction(
+    payload_json: String,
+    seed_words: Vec<String>,
+    passphrase: Option<String>,
+    confirmed: OfflineTransactionSummaryDto,
+) -> Result<OfflineTransactionPayloadDto> {
+    let payload: OfflinePayload<PrepareOneSidedTransactionForSigningResult> =
+        decode_payload(&payload_json, PayloadKind::Unsigned)?;
+    if summarize_payload(&payload)? != confirmed {
+        bail!("The transaction does not match the one confirmed for signing");
+    }
+    let network = parse_network(Some(payload.network.clone()))?;
+
+    let key_manager = derive_key_manager(&seed_words, passphrase.as_deref())?;
+    let consensus_constants = ConsensusConstantsBuilder::new(network).build();
+    let signed_tx = sign_locked_transaction(
+        &key_manager,
+        consensus_constants,
+        network,
+        payload.transaction,
+    )
+    .map_err(|e| TransactionError::SigningError(e.to_string()))?;
+
+    encode_payload(&OfflinePayload {
+        version: PAYLOAD_VERSION,
+        kind: PayloadKind::Signed,
+        network: payload.network,
+        wallet_name: payload.wallet_name,
+        transaction: signed_tx,
+    })
+}
+
+/// Finalizes a transaction signed offline and broadcasts it from the watch-only device.
+#[frb]
+pub async fn broadcast_signed_transaction(
+    payload_json: String,
+    passphrase: Option<String>,
+    base_url: Option<String>,
+    confirmation_window: Option<u64>,
+    proxy: Option<ProxySettingsDto>,
+) -> Result<DisplayedTransactionDto> {
+    let payload: OfflinePayload<SignedOneSidedTransactionResult> =
+        decode_payload(&payload_json, PayloadKind::Signed)?;
+    let conn = get_db_connection()?;
+    let network = resolve_wallet_network(&conn, &payload.wallet_name, Some(payload.network))?;
+    let profile = resolve_network_profile(&conn, network)?;
+    let candidates = node_candidates(&conn, network, base_url)?;
+    drop(conn);
+    let proxy = resolve_proxy(proxy.as_ref())?;
+    let base_url = select_healthy_endpoint(&candidates, None, proxy.as_ref())
+        .await
+        .map_err(|e| TransactionError::NetworkError(e.to_string()))?;
+
+    let mut sender = create_transaction_sender(
+        passphrase.as_deref(),
+        &payload.wallet_name,
+        network,
+        confirmation_window.unwrap_or(profile.confirmation_window),
+    )?;
+
+    let endpoint = proxied_endpoint(&base_url, proxy.as_ref())
+        .await
+        .map_err(|e| TransactionError::NetworkError(e.to_string()))?;
+    let result_tx = sender
+        .finalize_transaction_and_broadcast(payload.transaction, endpoint.url().to_string())
+        .await;
+    notify_balance_changed();
+    let result_tx = result_tx.map_err(|e| TransactionError::NetworkError(e.to_string()))?;
+
+    Ok(result_tx.into())
+}
+
+/// Reassembles payload JSON from scanned QR chunks, accepted in any order.
+#[frb]
+pub fn decode_qr_chunks(chunks: Vec<String>) -> Result<String> {
+    let mut parts: Vec<(usize, usize, String)> = chunks
+        .iter()
+        .map(|chunk| parse_qr_chunk(chunk.trim()))
+        .collect::<Result<_>>()?;
+    parts.sort_by_key(|(index, _, _)| *index);
+    // The same code is often scanned more than once; only a different payload is an error.
+    if let Some(pair) = parts
+        .windows(2)
+        .find(|pair| pair[0].0 == pair[1].0 && !pair[0].2.eq_ignore_ascii_case(&pair[1].2))
+    {
+        bail!("QR chunk {} was scanned from different payloads", pair[0].0);
+    }
+    parts.dedup_by_key(|(index, _, _)| *index);
+
+    let total = parts.first().map(|(_, total, _)| *total).unwrap_or(0);
+    if parts.iter().any(|(_, t, _)| *t != total) {
+        bail!("QR chunks belong to different payloads");
+    }
+    if parts.len() != total {
+        bail!("Missing QR chunks: have {} of {}", parts.len(), total);
+    }
+
+    let hex: String = parts.into_iter().map(|(_, _, data)| data).collect();
+    let bytes = from_hex(&hex.to_lowercase()).map_err(|e| anyhow!("Invalid QR data: {}", e))?;
+    String::from_utf8(bytes).context("QR data is not valid UTF-8")
+}
+
+fn summarize_payload(
+    payload: &OfflinePayload<PrepareOneSidedTransactionForSigningResult>,
+) -> Result<OfflineTransactionSummaryDto> {
+    let info = &payload.transaction.info;
+    let recipients: Vec<OfflineRecipientDto> = info
+        .recipients
+        .iter()
+        .map(|r| OfflineRecipientDto {
+            address: r.address.to_base58(),
+            amount: r.amount.as_u64(),
+            payment_id: r.payment_id.payment_id_as_string(),
+        })
+        .collect();
+    let total = recipients
+        .iter()
+        .try_fold(info.fee.as_u64(), |total, r| total.checked_add(r.amount))
+        .context("Transaction amounts overflow")?;
+
+    Ok(OfflineTransactionSummaryDto {
+        network: payload.network.clone(),
+        wallet_name: payload.wallet_name.clone(),
+        recipients,
+        fee: info.fee.as_u64(),
+        total,
+    })
+}
+
+fn encode_payload<T: Serialize>(
+    payload: &OfflinePayload<T>,
+) -> Result<OfflineTransactionPayloadDto> {
+    let json = serde_json::to_string(payload).context("Failed to serialize payload")?;
+    let qr_chunks = encode_qr_chunks(&json);
+
+    Ok(OfflineTransactionPayloadDto { json, qr_chunks })
+}
+
+fn encode_qr_chunks(json: &str) -> Vec<String> {
+    let hex = to_hex(json.as_bytes()).to_uppercase();
+    let data_chunks: Vec<&str> = hex
+        .as_bytes()
+        .chunks(QR_CHUNK_DATA_SIZE)
+        .map(|c| std::str::from_utf8(c).expect("hex is ASCII"))
+        .collect();
+    let total = data_chunks.len();
+    data_chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, data)| format!("{}:{}/{}:{}", QR_CHUNK_PREFIX, i + 1, total, data))
+        .collect()
+}
+
+fn decode_payload<T: DeserializeOwned>(
+    json: &str,
+    expected: PayloadKind,
+) -> Result<OfflinePayload<T>> {
+    let header: serde_json::Value =
+        serde_json::from_str(json).context("Payload is not valid JSON")?;
+    let version = header.get("version").and_then(|v| v.as_u64());
+    if version != Some(u64::from(PAYLOAD_VERSION)) {
+        bail!("Unsupported payload version: {:?}", version);
+    }
+
+    let payload: OfflinePayload<T> =
+        serde_json::from_value(header).context("Payload does not match the expected format")?;
+    if payload.kind != expected {
+        bail!("Expected a {:?} payload, got {:?}", expected, payload.kind);
+    }
+
+    Ok(payload)
+}
+
+fn parse_qr_chunk(chunk: &str) -> Result<(usize, usize, String)> {
+    let mut fields = chunk.splitn(3, ':');
+    let (Some(prefix), Some(position), Some(data)) = (fields.next(), fields.next(), fields.next())
+    else {
+        bail!("Malformed QR chunk");
+    };
+    if prefix != QR_CHUNK_PREFIX {
+        bail!("Not a transaction QR chunk");
+    }
+
+    let (index, total) = position
+        .split_once('/')
+        .context("Malformed QR chunk position")?;
+    let index: usize = index.parse().context("Malformed QR chunk index")?;
+    let total: usize = total.parse().context("Malformed QR chunk count")?;
+    if index == 0 || index > total {
+        bail!("QR chunk {} is out of range", index);
+    }
+
+    Ok((index, total, data.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks_of(note: &str) -> Vec<String> {
+        let json = serde_json::json!({ "version": PAYLOAD_VERSION, "note": note.repeat(1200) });
+        let chunks = encode_qr_chunks(&json.to_string());
+        assert_eq!(chunks.len(), 3);
+        chunks
+    }
+
+    #[test]
+    fn reassembles_chunks_in_any_order() {
+        let mut chunks = chunks_of("a");
+        let expected = decode_qr_chunks(chunks.clone()).unwrap();
+        chunks.reverse();
+        chunks.push(chunks[0].clone());
+
+        let json = decode_qr_chunks(chunks).unwrap();
+
+        assert_eq!(json, expected);
+        assert!(json.contains(&"a".repeat(1200)));
+    }
+
+    #[test]
+    fn rejects_missing_chunks() {
+        let mut chunks = chunks_of("a");
+        let total = chunks.len();
+        chunks.remove(1);
+
+        let error = decode_qr_chunks(chunks).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!("Missing QR chunks: have {} of {}", total - 1, total)
+        );
+    }
+
+    #[test]
+    fn rejects_chunks_from_different_payloads() {
+        let mut chunks = chunks_of("a");
+        chunks.push(chunks_of("b")[1].clone());
+
+        let error = decode_qr_chunks(chunks).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "QR chunk 2 was scanned from different payloads"
+        );
+    }
+
+    #[test]
+    fn rejects_chunks_with_different_counts() {
+        let mut chunks = chunks_of("a");
+        chunks[0] = chunks[0].replacen("/3:", "/4:", 1);
+
+        let error = decode_qr_chunks(chunks).unwrap_err();
+
+        assert_eq!(error.to_string(), "QR chunks belong to different payloads");
+    }
+}
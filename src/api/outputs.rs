@@ -0,0 +1,581 @@
+use crate::api::accounts::resolve_account;
+use crate::api::db::get_db_connection;
+use crate::api::network::resolve_wallet_network;
+use crate::api::network_profiles::resolve_network_profile;
+use crate::api::send_transaction::SECONDS_TO_LOCK_UTXO;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, NaiveDateTime, Utc};
+use flutter_rust_bridge::frb;
+use r2d2_sqlite::rusqlite::types::{Type, Value};
+use r2d2_sqlite::rusqlite::{self, params, Connection, Row};
+use tari_transaction_components::transaction_components::{MemoField, OutputType};
+
+#[frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputStatusDto {
+    Unspent,
+    /// Reserved by a send that has not been confirmed yet.
+    Locked,
+    Spent,
+    /// Received but not yet past the confirmation window.
+    Pending,
+}
+
+#[frb]
+#[derive(Clone, Debug, Default)]
+pub struct OutputFilterDto {
+    /// Only outputs in one of these states; all states when `None`.
+    pub statuses: Option<Vec<OutputStatusDto>>,
+    pub min_value: Option<u64>,
+    pub max_value: Option<u64>,
+}
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct OutputDto {
+    pub output_hash: String,
+    pub commitment: String,
+    pub value: u64,
+    pub status: OutputStatusDto,
+    pub output_type: String,
+    pub mined_height: u64,
+    /// Height from which the output can be spent, taking coinbase maturity and script locks
+    /// into account.
+    pub maturity_height: u64,
+    pub is_mature: bool,
+    /// RFC 3339 time when a send's lock on the output lapses.
+    pub lock_expires_at: Option<String>,
+    pub payment_id: Option<String>,
+    pub created_by_transaction_id: Option<String>,
+    /// Request id of the pending send that locked the output. This is not a transaction id;
+    /// the send gets one once it completes.
+    pub locked_by_request_id: Option<String>,
+    /// The completed send that spent the output. `None` when it was spent by a transaction
+    /// this wallet did not build, such as one sent from another device.
+    pub spent_by_transaction_id: Option<String>,
+    pub spent_height: Option<u64>,
+}
+
+/// Lists the account's outputs, oldest first, for inspecting where funds are.
+#[frb]
+pub fn list_outputs(
+    wallet_name: Option<String>,
+    account_id: Option<i64>,
+    filter: Option<OutputFilterDto>,
+) -> Result<Vec<OutputDto>> {
+    let filter = filter.unwrap_or_default();
+    let mut conn = get_db_connection()?;
+    let account = resolve_account(&mut conn, wallet_name.as_deref(), account_id)?;
+    let network = resolve_wallet_network(&conn, &account.friendly_name, None)?;
+    let confirmation_window = resolve_network_profile(&conn, network)?.confirmation_window;
+    let tip_height = scanned_tip_height(&conn, account.id)?;
+
+    let outputs = load_outputs(&conn, account.id, true)?
+        .into_iter()
+        .map(|output| {
+            let status = if output.is_spent() {
+                OutputStatusDto::Spent
+            } else if output.is_locked() {
+                OutputStatusDto::Locked
+            } else if !output.is_confirmed(tip_height, confirmation_window) {
+                OutputStatusDto::Pending
+            } else {
+                OutputStatusDto::Unspent
+            };
+            OutputDto {
+                lock_expires_at: output.lock_expires_at(),
+                is_mature: output.spendable_height() <= tip_height,
+                maturity_height: output.spendable_height(),
+                output_hash: output.output_hash,
+                commitment: output.commitment,
+                value: output.value,
+                status,
+                output_type: output.output_type.to_string(),
+                mined_height: output.mined_height,
+                payment_id: output.payment_id,
+                created_by_transaction_id: output.transaction_id,
+                locked_by_request_id: output.locked_by,
+                spent_by_transaction_id: output.spent_by_transaction_id,
+                spent_height: output.spent_height,
+            }
+        })
+        .filter(|output| {
+            filter
+                .statuses
+                .as_ref()
+                .is_none_or(|statuses| statuses.contains(&output.status))
+                && filter.min_value.is_none_or(|min| output.value >= min)
+                && filter.max_value.is_none_or(|max| output.value <= max)
+        })
+        .collect();
+
+    Ok(outputs)
+}
+
+/// The `status` column of minotari's `outputs` table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum StoredOutputStatus {
+    Unspent,
+    Locked,
+    Spent,
+}
+
+impl StoredOutputStatus {
+    fn parse(status: &str) -> Option<Self> {
+        match status.to_ascii_uppercase().as_str() {
+            "UNSPENT" => Some(Self::Unspent),
+            "LOCKED" => Some(Self::Locked),
+            "SPENT" => Some(Self::Spent),
+            _ => None,
+        }
+    }
+}
+
+/// An output owned by an account, read straight from minotari's `outputs` table.
+///
+/// Rows are decoded strictly: a missing value or one of an unexpected type fails the read
+/// instead of being taken as zero, which would make unconfirmed or immature funds look spendable.
+#[frb(ignore)]
+#[derive(Clone, Debug)]
+pub(crate) struct OutputRecord {
+    pub output_hash: String,
+    pub commitment: String,
+    pub value: u64,
+    pub mined_height: u64,
+    pub status: StoredOutputStatus,
+    pub locked_at: Option<DateTime<Utc>>,
+    pub locked_by: Option<String>,
+    pub output_type: OutputType,
+    /// Height from which the output's features allow it to be spent.
+    pub maturity_height: u64,
+    /// Height from which the output's script allows it to be spent.
+    pub script_lock_height: u64,
+    pub payment_id: Option<String>,
+    pub transaction_id: Option<String>,
+    /// Height of the block that spent the output, if it has been spent.
+    pub spent_height: Option<u64>,
+    /// The completed send that spent the output, if this wallet built it.
+    pub spent_by_transaction_id: Option<String>,
+}
+
+impl OutputRecord {
+    pub fn is_locked(&self) -> bool {
+        self.status == StoredOutputStatus::Locked
+    }
+
+    pub fn is_spent(&self) -> bool {
+        self.spent_height.is_some()
+    }
+
+    pub fn is_coinbase(&self) -> bool {
+        self.output_type == OutputType::Coinbase
+    }
+
+    pub fn is_confirmed(&self, tip_height: u64, confirmation_window: u64) -> bool {
+        tip_height >= self.confirmed_at_height(confirmation_window)
+    }
+
+    pub fn confirmed_at_height(&self, confirmation_window: u64) -> u64 {
+        self.mined_height.saturating_add(confirmation_window)
+    }
+
+    /// When a send's lock on this output lapses, as an RFC 3339 time.
+    pub fn lock_expires_at(&self) -> Option<String> {
+        self.locked_at
+            .filter(|_| self.is_locked())
+            .map(|locked_at| {
+                (locked_at + ChronoDuration::seconds(SECONDS_TO_LOCK_UTXO as i64)).to_rfc3339()
+            })
+    }
+
+    /// The height from which both the output's features and its script allow spending it.
+    pub fn spendable_height(&self) -> u64 {
+        self.maturity_height.max(self.script_lock_height)
+    }
+}
+
+const OUTPUT_COLUMNS: &str = "o.output_hash, json_extract(o.wallet_output_json, '$.commitment'),
+    o.value, o.mined_in_block_height, o.status, o.locked_at,
+    json_extract(o.wallet_output_json, '$.features.output_type'),
+    json_extract(o.wallet_output_json, '$.features.maturity'),
+    json_extract(o.wallet_output_json, '$.script_lock_height'),
+    o.memo_parsed, o.tx_id, o.locked_by_request_id, i.mined_in_block_height,
+    CASE WHEN i.id IS NOT NULL THEN
+        (SELECT c.id FROM completed_transactions c WHERE c.pending_tx_id = o.locked_by_request_id)
+    END";
+
+/// Outputs of the account that have not been spent, including ones locked by pending sends.
+pub(crate) fn load_unspent_outputs(
+    conn: &Connection,
+    account_id: i64,
+) -> Result<Vec<OutputRecord>> {
+    load_outputs(conn, account_id, false)
+}
+
+/// Every output the account has received, spent ones included when `include_spent` is set.
+pub(crate) fn load_outputs(
+    conn: &Connection,
+    account_id: i64,
+    include_spent: bool,
+) -> Result<Vec<OutputRecord>> {
+    let sql = format!(
+        "SELECT {} FROM outputs o
+         LEFT JOIN inputs i ON i.output_id = o.id AND i.deleted_at IS NULL
+         WHERE o.account_id = ?1
+           AND o.deleted_at IS NULL
+           AND (?2 OR i.id IS NULL)
+         ORDER BY o.mined_in_block_height, o.id",
+        OUTPUT_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let outputs = stmt
+        .query_map(params![account_id, include_spent], output_record_from_row)?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to read wallet outputs")?;
+    Ok(outputs)
+}
+
+/// The highest block the account has been scanned to, or 0 before the first scan.
+pub(crate) fn scanned_tip_height(conn: &Connection, account_id: i64) -> Result<u64> {
+    let height: Option<i64> = conn
+        .query_row(
+            "SELECT MAX(height) FROM scanned_tip_blocks WHERE account_id = ?1",
+            params![account_id],
+            |row| row.get(0),
+        )
+        .context("Failed to read scanned tip height")?;
+    Ok(height.unwrap_or(0).max(0) as u64)
+}
+
+/// The user data in the payment id of each output the account received, with the transaction
+/// the output arrived in. Outputs without user data, or whose payment id cannot be read, are
+/// left out.
+pub(crate) fn load_received_payment_ids(
+    conn: &Connection,
+    account_id: i64,
+) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut stmt = conn.prepare(
+        "SELECT o.tx_id, json_extract(o.wallet_output_json, '$.payment_id') FROM outputs o
+         WHERE o.account_id = ?1 AND o.deleted_at IS NULL AND o.tx_id IS NOT NULL",
+    )?;
+    let rows = stmt
+        .query_map(params![account_id], |row| {
+            Ok((optional_text(row, 0)?, row.get::<_, Option<String>>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to read output payment ids")?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(tx_id, memo)| {
+            let memo: MemoField = serde_json::from_str(&memo?).ok()?;
+            Some((tx_id?, memo.get_payment_id()))
+        })
+        .filter(|(_, payment_id)| !payment_id.is_empty())
+        .collect())
+}
+
+/// Funds entering or leaving the account in one block.
+#[frb(ignore)]
+#[derive(Clone, Debug)]
+pub(crate) struct BalanceMovement {
+    pub height: u64,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub credit: u64,
+    pub debit: u64,
+}
+
+/// Received outputs and spent inputs in block order.
+///
+/// Rows rolled back by a reorg are soft-deleted by the scanner and excluded here.
+pub(crate) fn load_balance_movements(
+    conn: &Connection,
+    account_id: i64,
+) -> Result<Vec<BalanceMovement>> {
+    let mut stmt = conn.prepare(
+        "SELECT height, timestamp, SUM(credit), SUM(debit) FROM (
+             SELECT o.mined_in_block_height AS height, o.mined_timestamp AS timestamp,
+                    o.value AS credit, 0 AS debit
+             FROM outputs o
+             WHERE o.account_id = ?1 AND o.deleted_at IS NULL
+               AND o.mined_in_block_height IS NOT NULL
+             UNION ALL
+             SELECT i.mined_in_block_height, i.mined_timestamp, 0, o.value
+             FROM inputs i JOIN outputs o ON o.id = i.output_id
+             WHERE i.account_id = ?1 AND i.deleted_at IS NULL
+               AND i.mined_in_block_height IS NOT NULL
+         )
+         GROUP BY height
+         ORDER BY height",
+    )?;
+    let movements = stmt
+        .query_map(params![account_id], |row| {
+            Ok(BalanceMovement {
+                height: required_height(row, 0)?,
+                timestamp: value_to_timestamp(row.get(1)?),
+                credit: row.get(2)?,
+                debit: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to read balance history")?;
+    Ok(movements)
+}
+
+fn output_record_from_row(row: &Row) -> rusqlite::Result<OutputRecord> {
+    let status: String = row.get(4)?;
+    let locked_at: Option<String> = row.get(5)?;
+    Ok(OutputRecord {
+        output_hash: required_hex(row, 0)?,
+        commitment: required_hex(row, 1)?,
+        value: row.get(2)?,
+        mined_height: required_height(row, 3)?,
+        status: StoredOutputStatus::parse(&status)
+            .ok_or_else(|| conversion_error(4, Type::Text, format!("status '{}'", status)))?,
+        locked_at: locked_at
+            .map(|text| {
+                parse_timestamp(&text)
+                    .ok_or_else(|| conversion_error(5, Type::Text, format!("lock time '{}'", text)))
+            })
+            .transpose()?,
+        output_type: required_output_type(row, 6)?,
+        maturity_height: required_height(row, 7)?,
+        script_lock_height: required_height(row, 8)?,
+        payment_id: optional_text(row, 9)?,
+        transaction_id: optional_text(row, 10)?,
+        locked_by: optional_text(row, 11)?,
+        spent_height: row.get(12)?,
+        spent_by_transaction_id: optional_text(row, 13)?,
+    })
+}
+
+fn conversion_error(column: usize, sql_type: Type, what: String) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(
+        column,
+        sql_type,
+        format!("unexpected {} in minotari's outputs table", what).into(),
+    )
+}
+
+/// A hash or commitment, stored either as a blob or as hex text.
+fn required_hex(row: &Row, column: usize) -> rusqlite::Result<String> {
+    match row.get::<_, Value>(column)? {
+        Value::Text(text) if !text.is_empty() => Ok(text),
+        Value::Blob(bytes) if !bytes.is_empty() => {
+            Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+        }
+        Value::Null => Err(rusqlite::Error::InvalidColumnType(
+            column,
+            format!("column {}", column),
+            Type::Null,
+        )),
+        other => Err(conversion_error(
+            column,
+            other.data_type(),
+            "hash value".to_string(),
+        )),
+    }
+}
+
+/// A block height; SQLite hands back numbers extracted from JSON as integers or text.
+fn required_height(row: &Row, column: usize) -> rusqlite::Result<u64> {
+    match row.get::<_, Value>(column)? {
+        Value::Integer(height) => u64::try_from(height)
+            .map_err(|_| conversion_error(column, Type::Integer, format!("height {}", height))),
+        Value::Text(text) => text
+            .parse()
+            .map_err(|_| conversion_error(column, Type::Text, format!("height '{}'", text))),
+        other => Err(rusqlite::Error::InvalidColumnType(
+            column,
+            format!("column {}", column),
+            other.data_type(),
+        )),
+    }
+}
+
+/// An output type, serialized either as its discriminant or as its variant name.
+fn required_output_type(row: &Row, column: usize) -> rusqlite::Result<OutputType> {
+    let (output_type, sql_type) = match row.get::<_, Value>(column)? {
+        Value::Integer(value) => (
+            u8::try_from(value).ok().and_then(OutputType::from_byte),
+            Type::Integer,
+        ),
+        Value::Text(text) => {
+            let output_type = match text.parse::<u8>() {
+                Ok(value) => OutputType::from_byte(value),
+                Err(_) => OutputType::all()
+                    .into_iter()
+                    .find(|output_type| output_type.to_string().eq_ignore_ascii_case(&text)),
+            };
+            (output_type, Type::Text)
+        }
+        other => {
+            return Err(rusqlite::Error::InvalidColumnType(
+                column,
+                format!("column {}", column),
+                other.data_type(),
+            ))
+        }
+    };
+    output_type.ok_or_else(|| conversion_error(column, sql_type, "output type".to_string()))
+}
+
+fn optional_text(row: &Row, column: usize) -> rusqlite::Result<Option<String>> {
+    match row.get::<_, Value>(column)? {
+        Value::Null => Ok(None),
+        Value::Text(text) => Ok(Some(text).filter(|t| !t.is_empty())),
+        Value::Integer(i) => Ok(Some(i.to_string())),
+        other => Err(conversion_error(
+            column,
+            other.data_type(),
+            "text value".to_string(),
+        )),
+    }
+}
+
+fn value_to_timestamp(value: Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::Integer(seconds) => DateTime::from_timestamp(seconds, 0),
+        Value::Text(text) => parse_timestamp(&text),
+        _ => None,
+    }
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|t| t.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S").map(|t| t.and_utc())
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tari_transaction_components::transaction_components::{
+        memo_field::TxType, OutputFeatures, RangeProofType,
+    };
+
+    const ACCOUNT_ID: i64 = 1;
+
+    fn outputs_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE outputs (
+                 id INTEGER PRIMARY KEY, account_id INTEGER, output_hash BLOB, value INTEGER,
+                 mined_in_block_height INTEGER, mined_timestamp TEXT, status TEXT, locked_at TEXT,
+                 wallet_output_json TEXT, memo_parsed TEXT, tx_id TEXT,
+                 locked_by_request_id TEXT, deleted_at TEXT
+             );
+             CREATE TABLE inputs (
+                 id INTEGER PRIMARY KEY, account_id INTEGER, output_id INTEGER,
+                 mined_in_block_height INTEGER, mined_timestamp TEXT, deleted_at TEXT
+             );
+             CREATE TABLE completed_transactions (id TEXT PRIMARY KEY, pending_tx_id TEXT);",
+        )
+        .unwrap();
+        conn
+    }
+
+    /// The fields of a serialized `WalletOutput` that the outputs query reads, with `features`
+    /// produced by the transaction components themselves.
+    fn wallet_output_json(features: serde_json::Value) -> String {
+        json!({
+            "version": "V0",
+            "value": 2_500_000,
+            "features": features,
+            "script_lock_height": 0,
+            "minimum_value_promise": 0,
+            "commitment": "a4f1c0e2d5b6978812345678901234567890abcdefabcdefabcdefabcdefab01",
+        })
+        .to_string()
+    }
+
+    fn insert_output(conn: &Connection, output_hash: &[u8], wallet_output_json: &str) {
+        conn.execute(
+            "INSERT INTO outputs (account_id, output_hash, value, mined_in_block_height, status,
+                                  wallet_output_json)
+             VALUES (?1, ?2, 2500000, 100, 'Unspent', ?3)",
+            params![ACCOUNT_ID, output_hash, wallet_output_json],
+        )
+        .unwrap();
+    }
+
+    fn coinbase_features() -> serde_json::Value {
+        let features = OutputFeatures::create_coinbase(820, None, RangeProofType::BulletProofPlus);
+        serde_json::to_value(features).unwrap()
+    }
+
+    #[test]
+    fn reads_output_type_serialized_as_discriminant() {
+        let conn = outputs_db();
+        let features = coinbase_features();
+        assert!(features["output_type"].is_u64());
+        insert_output(&conn, &[1; 32], &wallet_output_json(features));
+
+        let outputs = load_unspent_outputs(&conn, ACCOUNT_ID).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert!(outputs[0].is_coinbase());
+        assert_eq!(outputs[0].maturity_height, 820);
+        assert_eq!(outputs[0].output_hash, "01".repeat(32));
+    }
+
+    #[test]
+    fn reads_output_type_serialized_as_name() {
+        let conn = outputs_db();
+        let mut features = coinbase_features();
+        features["output_type"] = json!("Coinbase");
+        insert_output(&conn, &[2; 32], &wallet_output_json(features));
+
+        let outputs = load_unspent_outputs(&conn, ACCOUNT_ID).unwrap();
+
+        assert!(outputs[0].is_coinbase());
+        assert_eq!(outputs[0].spendable_height(), 820);
+    }
+
+    #[test]
+    fn rejects_unknown_output_type() {
+        let conn = outputs_db();
+        for (hash, output_type) in [(3u8, json!("Mystery")), (4, json!(200))] {
+            let mut features = coinbase_features();
+            features["output_type"] = output_type;
+            insert_output(&conn, &[hash; 32], &wallet_output_json(features));
+        }
+
+        assert!(load_unspent_outputs(&conn, ACCOUNT_ID).is_err());
+    }
+
+    #[test]
+    fn reads_payment_id_user_data_of_received_outputs() {
+        let conn = outputs_db();
+        let memos = [
+            (
+                "tx-1",
+                MemoField::new_open(b"invoice-42".to_vec(), TxType::PaymentToOther),
+            ),
+            ("tx-2", Ok(MemoField::new_empty())),
+        ];
+        for (hash, (tx_id, memo)) in memos.into_iter().enumerate() {
+            let mut output: serde_json::Value =
+                serde_json::from_str(&wallet_output_json(coinbase_features())).unwrap();
+            output["payment_id"] = serde_json::to_value(memo.unwrap()).unwrap();
+            conn.execute(
+                "INSERT INTO outputs (account_id, output_hash, value, status, wallet_output_json,
+                                      tx_id)
+                 VALUES (?1, ?2, 2500000, 'Unspent', ?3, ?4)",
+                params![ACCOUNT_ID, [hash as u8; 32], output.to_string(), tx_id],
+            )
+            .unwrap();
+        }
+
+        let payment_ids = load_received_payment_ids(&conn, ACCOUNT_ID).unwrap();
+
+        assert_eq!(
+            payment_ids,
+            vec![("tx-1".to_string(), b"invoice-42".to_vec())]
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use crate::api::address::parse_address;
+use crate::api::network::parse_network;
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use thiserror::Error;
+
+const URI_SCHEME: &str = "tari:";
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct PaymentRequestDto {
+    pub recipient_address: String,
+    /// Amount in MicroMinotari.
+    pub amount: Option<u64>,
+    pub payment_id: Option<String>,
+    pub label: Option<String>,
+    pub network: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum PaymentUriError {
+    #[error("Not a tari: payment URI")]
+    InvalidScheme,
+
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
+
+    #[error("Invalid network: {0}")]
+    InvalidNetwork(String),
+
+    #[error("Address is for {address} but the request is for {requested}")]
+    NetworkMismatch { address: String, requested: String },
+
+    #[error("Duplicate parameter: {0}")]
+    DuplicateParameter(String),
+
+    #[error("Malformed parameter: {0}")]
+    MalformedParameter(String),
+}
+
+/// Encodes a payment request as `tari:<address>?amount=..&payment_id=..&label=..&network=..`.
+///
+/// The result is plain ASCII and can be rendered as a QR code as-is.
+#[frb]
+pub fn encode_payment_uri(request: PaymentRequestDto) -> Result<String> {
+    let address = parse_address(&request.recipient_address)
+        .map_err(|e| PaymentUriError::InvalidAddress(e.to_string()))?;
+    let address_network = address.network();
+    if let Some(network) = request.network {
+        let network = parse_network(Some(network))
+            .map_err(|e| PaymentUriError::InvalidNetwork(e.to_string()))?;
+        if network != address_network {
+            return Err(PaymentUriError::NetworkMismatch {
+                address: address_network.to_string(),
+                requested: network.to_string(),
+            }
+            .into());
+        }
+    }
+    if request.amount == Some(0) {
+        return Err(PaymentUriError::InvalidAmount("must be greater than zero".to_string()).into());
+    }
+
+    let mut params = vec![];
+    if let Some(amount) = request.amount {
+        params.push(format!("amount={}", amount));
+    }
+    if let Some(payment_id) = request.payment_id {
+        params.push(format!("payment_id={}", encode_component(&payment_id)));
+    }
+    if let Some(label) = request.label {
+        params.push(format!("label={}", encode_component(&label)));
+    }
+    params.push(format!("network={}", address_network));
+
+    Ok(format!(
+        "{}{}?{}",
+        URI_SCHEME,
+        address.to_base58(),
+        params.join("&")
+    ))
+}
+
+/// Parses a `tari:` URI into a prefilled payment request.
+///
+/// Fails if the URI's network disagrees with its address or with `expected_network`.
+#[frb]
+pub fn parse_payment_uri(
+    uri: String,
+    expected_network: Option<String>,
+) -> Result<PaymentRequestDto> {
+    let uri = uri.trim();
+    let rest = uri
+        .get(..URI_SCHEME.len())
+        .filter(|scheme| scheme.eq_ignore_ascii_case(URI_SCHEME))
+        .map(|_| &uri[URI_SCHEME.len()..])
+        .ok_or(PaymentUriError::InvalidScheme)?;
+    let rest = rest.trim_start_matches("//");
+    let (address_part, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+    let address_input = decode_component(address_part)?;
+    let address = parse_address(&address_input)
+        .map_err(|e| PaymentUriError::InvalidAddress(e.to_string()))?;
+    let address_network = address.network();
+
+    let mut request = PaymentRequestDto {
+        recipient_address: address.to_base58(),
+        amount: None,
+        payment_id: None,
+        label: None,
+        network: Some(address_network.to_string()),
+    };
+    let mut network_param = None;
+
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| PaymentUriError::MalformedParameter(pair.to_string()))?;
+        let value = decode_component(value)?;
+        let slot = match key {
+            "amount" => {
+                let amount = value
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|a| *a > 0)
+                    .ok_or_else(|| PaymentUriError::InvalidAmount(value.clone()))?;
+                if request.amount.replace(amount).is_some() {
+                    return Err(PaymentUriError::DuplicateParameter(key.to_string()).into());
+                }
+                continue;
+            }
+            "payment_id" => &mut request.payment_id,
+            "label" => &mut request.label,
+            "network" => &mut network_param,
+            // Unknown parameters are ignored so newer wallets can extend the format.
+            _ => continue,
+        };
+        if slot.replace(value).is_some() {
+            return Err(PaymentUriError::DuplicateParameter(key.to_string()).into());
+        }
+    }
+
+    for network in [network_param, expected_network].into_iter().flatten() {
+        let network = parse_network(Some(network))
+            .map_err(|e| PaymentUriError::InvalidNetwork(e.to_string()))?;
+        if network != address_network {
+            return Err(PaymentUriError::NetworkMismatch {
+                address: address_network.to_string(),
+                requested: network.to_string(),
+            }
+            .into());
+        }
+    }
+
+    Ok(request)
+}
+
+fn encode_component(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
+}
+
+fn decode_component(value: &str) -> Result<String, PaymentUriError> {
+    percent_decode_str(value)
+        .decode_utf8()
+        .map(|v| v.into_owned())
+        .map_err(|_| PaymentUriError::MalformedParameter(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tari_common::configuration::Network;
+    use tari_common_types::tari_address::TariAddress;
+    use tari_common_types::types::PrivateKey;
+    use tari_crypto::compressed_key::CompressedKey;
+    use tari_utilities::hex::Hex;
+
+    fn address(network: Network) -> String {
+        let key = |scalar: u8| {
+            let mut bytes = [0u8; 32];
+            bytes[0] = scalar;
+            CompressedKey::from_secret_key(&PrivateKey::from_hex(&bytes.to_hex()).unwrap())
+        };
+        TariAddress::new_dual_address_with_default_features(key(1), key(2), network)
+            .unwrap()
+            .to_base58()
+    }
+
+    fn request(recipient_address: String) -> PaymentRequestDto {
+        PaymentRequestDto {
+            recipient_address,
+            amount: None,
+            payment_id: None,
+            label: None,
+            network: None,
+        }
+    }
+
+    fn uri_error(result: Result<impl std::fmt::Debug>) -> PaymentUriError {
+        result
+            .unwrap_err()
+            .downcast::<PaymentUriError>()
+            .expect("not a payment URI error")
+    }
+
+    #[test]
+    fn parses_what_it_encodes() {
+        let recipient = address(Network::MainNet);
+        let uri = encode_payment_uri(PaymentRequestDto {
+            amount: Some(1_500_000),
+            payment_id: Some("invoice 42".to_string()),
+            label: Some("Coffee & cake".to_string()),
+            ..request(recipient.clone())
+        })
+        .unwrap();
+
+        let parsed = parse_payment_uri(uri, Some(Network::MainNet.to_string())).unwrap();
+
+        assert_eq!(parsed.recipient_address, recipient);
+        assert_eq!(parsed.amount, Some(1_500_000));
+        assert_eq!(parsed.payment_id.as_deref(), Some("invoice 42"));
+        assert_eq!(parsed.label.as_deref(), Some("Coffee & cake"));
+        assert_eq!(parsed.network, Some(Network::MainNet.to_string()));
+    }
+
+    #[test]
+    fn rejects_duplicate_parameters() {
+        let recipient = address(Network::MainNet);
+        for query in [
+            "amount=1&amount=2",
+            "label=a&label=b",
+            "payment_id=1&payment_id=1",
+        ] {
+            let uri = format!("tari:{}?{}", recipient, query);
+
+            let error = uri_error(parse_payment_uri(uri, None));
+
+            assert!(
+                matches!(error, PaymentUriError::DuplicateParameter(_)),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_zero_amount() {
+        let recipient = address(Network::MainNet);
+
+        let encoded = encode_payment_uri(PaymentRequestDto {
+            amount: Some(0),
+            ..request(recipient.clone())
+        });
+        let parsed = parse_payment_uri(format!("tari:{}?amount=0", recipient), None);
+
+        assert!(matches!(
+            uri_error(encoded),
+            PaymentUriError::InvalidAmount(_)
+        ));
+        assert!(matches!(
+            uri_error(parsed),
+            PaymentUriError::InvalidAmount(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_network_mismatch() {
+        let recipient = address(Network::Esmeralda);
+        let mainnet = Network::MainNet.to_string();
+
+        let encoded = encode_payment_uri(PaymentRequestDto {
+            network: Some(mainnet.clone()),
+            ..request(recipient.clone())
+        });
+        let in_uri = parse_payment_uri(format!("tari:{}?network={}", recipient, mainnet), None);
+        let expected = parse_payment_uri(format!("tari:{}", recipient), Some(mainnet));
+
+        for error in [uri_error(encoded), uri_error(in_uri), uri_error(expected)] {
+            assert!(matches!(error, PaymentUriError::NetworkMismatch { .. }));
+        }
+    }
+
+    #[test]
+    fn decodes_percent_encoded_labels() {
+        let recipient = address(Network::MainNet);
+        let uri = format!(
+            "tari:{}?label=Caf%C3%A9%20%26%20Bar%3F&payment_id=a%3Db",
+            recipient
+        );
+
+        let parsed = parse_payment_uri(uri, None).unwrap();
+
+        assert_eq!(parsed.label.as_deref(), Some("Café & Bar?"));
+        assert_eq!(parsed.payment_id.as_deref(), Some("a=b"));
+        assert!(matches!(
+            uri_error(parse_payment_uri(
+                format!("tari:{}?label=%FF", recipient),
+                None
+            )),
+            PaymentUriError::MalformedParameter(_)
+        ));
+    }
+}
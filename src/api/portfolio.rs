@@ -0,0 +1,83 @@
+use crate::api::accounts::AccountDto;
+use crate::api::balance::AccountBalanceDto;
+use crate::api::db::get_db_connection;
+use crate::api::wallet::find_wallet_record;
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use minotari_wallet::{get_accounts, get_balance as get_wallet_balance};
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct WalletPortfolioDto {
+    pub wallet_name: String,
+    pub total: AccountBalanceDto,
+    pub accounts: Vec<AccountDto>,
+}
+
+/// Network group for wallets that have no network recorded, such as ones created by other tools.
+const UNKNOWN_NETWORK: &str = "unknown";
+
+/// Totals for one network; balances on different networks are never added together.
+#[frb]
+#[derive(Clone, Debug)]
+pub struct NetworkPortfolioDto {
+    /// The network name, or "unknown" for wallets whose network is not recorded.
+    pub network: String,
+    pub total: AccountBalanceDto,
+    pub wallets: Vec<WalletPortfolioDto>,
+}
+
+/// Adds up every account in the open database, grouped by network and then by wallet.
+#[frb]
+pub fn get_portfolio() -> Result<Vec<NetworkPortfolioDto>> {
+    let mut conn = get_db_connection()?;
+    let accounts = get_accounts(&mut conn, None)?;
+
+    let mut networks: Vec<NetworkPortfolioDto> = Vec::new();
+    for account in accounts {
+        let network = match find_wallet_record(&conn, &account.friendly_name)? {
+            Some(record) => record.network.to_string(),
+            None => UNKNOWN_NETWORK.to_string(),
+        };
+        let balance: AccountBalanceDto = get_wallet_balance(&mut conn, account.id)?.into();
+
+        let network_index = match networks.iter().position(|n| n.network == network) {
+            Some(index) => index,
+            None => {
+                networks.push(NetworkPortfolioDto {
+                    network,
+                    total: AccountBalanceDto::default(),
+                    wallets: Vec::new(),
+                });
+                networks.len() - 1
+            }
+        };
+        let portfolio = &mut networks[network_index];
+        portfolio.total.accumulate(&balance);
+
+        let wallet_index = match portfolio
+            .wallets
+            .iter()
+            .position(|w| w.wallet_name == account.friendly_name)
+        {
+            Some(index) => index,
+            None => {
+                portfolio.wallets.push(WalletPortfolioDto {
+                    wallet_name: account.friendly_name.clone(),
+                    total: AccountBalanceDto::default(),
+                    accounts: Vec::new(),
+                });
+                portfolio.wallets.len() - 1
+            }
+        };
+        let wallet = &mut portfolio.wallets[wallet_index];
+        wallet.total.accumulate(&balance);
+        wallet.accounts.push(AccountDto {
+            account_id: account.id,
+            wallet_name: account.friendly_name,
+            balance,
+        });
+    }
+
+    Ok(networks)
+}
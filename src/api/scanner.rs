@@ -1,10 +1,20 @@
+use crate::api::accounts::resolve_account;
+use crate::api::balance::notify_balance_changed;
+use crate::api::contacts::apply_contact_labels;
+use crate::api::db::get_db_connection;
+use crate::api::integrated_addresses::apply_integrated_addresses;
+use crate::api::network::{resolve_default_network, resolve_wallet_network};
+use crate::api::network_profiles::resolve_network_profile;
+use crate::api::node::{backoff_delay, is_node_healthy, node_candidates, select_healthy_endpoint};
+use crate::api::proxy::{proxied_endpoint, resolve_proxy, ProxySettingsDto};
 use crate::api::transactions::DisplayedTransactionDto;
 use crate::{api::db::get_db_path, frb_generated::StreamSink};
 use anyhow::{anyhow, Result};
 use flutter_rust_bridge::frb;
 use minotari_wallet::scan::{DisplayedTransactionsEvent, TransactionsUpdatedEvent};
 use minotari_wallet::{ProcessingEvent, ScanMode, ScanStatusEvent, Scanner};
-use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
@@ -28,9 +38,25 @@ pub enum ScanEventDto {
     Status(ScanStatusDto),
     TransactionsReady(TransactionsReadyDto),
     TransactionsUpdated(TransactionsUpdatedDto),
+    /// The scanner lost its base node and continued on another one.
+    NodeChanged {
+        from_url: String,
+        to_url: String,
+    },
     Error(String),
 }
 
+impl ScanEventDto {
+    fn account_id(&self) -> Option<i64> {
+        match self {
+            ScanEventDto::Status(status) => Some(status.account_id()),
+            ScanEventDto::TransactionsReady(e) => Some(e.account_id),
+            ScanEventDto::TransactionsUpdated(e) => Some(e.account_id),
+            ScanEventDto::NodeChanged { .. } | ScanEventDto::Error(_) => None,
+        }
+    }
+}
+
 #[frb]
 #[derive(Clone)]
 pub enum ScanStatusDto {
@@ -63,6 +89,19 @@ pub enum ScanStatusDto {
     },
 }
 
+impl ScanStatusDto {
+    fn account_id(&self) -> i64 {
+        match self {
+            ScanStatusDto::Started { account_id, .. }
+            | ScanStatusDto::Progress { account_id, .. }
+            | ScanStatusDto::Completed { account_id, .. }
+            | ScanStatusDto::Paused { account_id, .. }
+            | ScanStatusDto::Waiting { account_id, .. }
+            | ScanStatusDto::MoreBlocksAvailable { account_id, .. } => *account_id,
+        }
+    }
+}
+
 impl From<ScanStatusEvent> for ScanStatusDto {
     fn from(e: ScanStatusEvent) -> Self {
         match e {
@@ -152,18 +191,77 @@ impl From<TransactionsUpdatedEvent> for TransactionsUpdatedDto {
     }
 }
 
+// Labels are a convenience; a scan must not stall because the address book is unavailable.
+fn label_counterparties(mut dto: ScanEventDto) -> ScanEventDto {
+    let (account_id, transactions) = match &mut dto {
+        ScanEventDto::TransactionsReady(e) => (e.account_id, &mut e.transactions),
+        ScanEventDto::TransactionsUpdated(e) => (e.account_id, &mut e.updated_transactions),
+        _ => return dto,
+    };
+    if let Ok(conn) = get_db_connection() {
+        let _ = apply_contact_labels(&conn, transactions);
+        let _ = apply_integrated_addresses(&conn, account_id, transactions);
+    }
+    dto
+}
+
 #[frb]
 pub struct ScanConfiguration {
     pub password: String,
-    pub base_url: String,
-    pub batch_size: u64,
+    /// Overrides the network profile's base node URL.
+    pub base_url: Option<String>,
+    /// Overrides the network profile's batch size.
+    pub batch_size: Option<u64>,
+    /// Network whose profile supplies the defaults; taken from the account's wallet when
+    /// `account_id` is set, and otherwise required unless all wallets share one network.
+    pub network: Option<String>,
     pub continuous: bool,
     pub poll_interval_seconds: u64,
+    /// When set, only this account's events are forwarded to the sink. The scanner selects
+    /// accounts by name, so other accounts sharing its name are scanned along with it.
+    pub account_id: Option<i64>,
+    /// Overrides the global proxy for this scan.
+    pub proxy: Option<ProxySettingsDto>,
+}
+
+const MAX_SCAN_FAILOVERS: u32 = 5;
+
+struct ScanTarget {
+    candidates: Vec<String>,
+    batch_size: u64,
+    /// Name of the accounts to scan; all accounts are scanned when `None`.
+    account_name: Option<String>,
+    proxy: Option<ProxySettingsDto>,
+}
+
+fn resolve_scan_target(config: &ScanConfiguration) -> Result<ScanTarget> {
+    let mut conn = get_db_connection()?;
+    let (network, account_name) = match config.account_id {
+        Some(account_id) => {
+            let account = resolve_account(&mut conn, None, Some(account_id))?;
+            let network =
+                resolve_wallet_network(&conn, &account.friendly_name, config.network.clone())?;
+            (network, Some(account.friendly_name))
+        }
+        None => (
+            resolve_default_network(&conn, config.network.clone())?,
+            None,
+        ),
+    };
+
+    let profile = resolve_network_profile(&conn, network)?;
+    Ok(ScanTarget {
+        candidates: node_candidates(&conn, network, config.base_url.clone())?,
+        batch_size: config.batch_size.unwrap_or(profile.batch_size),
+        account_name,
+        proxy: resolve_proxy(config.proxy.as_ref())?,
+    })
 }
 
 #[frb]
 pub async fn start_scan(sink: StreamSink<ScanEventDto>, config: ScanConfiguration) -> Result<()> {
     let db_path = get_db_path()?;
+    let target = resolve_scan_target(&config)?;
 
     let cancel_token = CancellationToken::new();
     {
@@ -171,51 +269,7 @@ pub async fn start_scan(sink: StreamSink<ScanEventDto>, config: ScanConfiguratio
         *guard = Some(cancel_token.clone());
     }
 
-    let mode = if config.continuous {
-        ScanMode::Continuous {
-            poll_interval: Duration::from_secs(config.poll_interval_seconds),
-        }
-    } else {
-        ScanMode::Full
-    };
-
-    let scanner_builder = Scanner::new(
-        &config.password,
-        &config.base_url,
-        &db_path,
-        config.batch_size,
-    )
-    .mode(mode)
-    .cancel_token(cancel_token.clone());
-
-    let (mut rx, scan_future) = scanner_builder.run_with_events();
-
-    let stream_sink = sink.clone();
-    let loop_cancel_token = cancel_token.clone();
-
-    tokio::spawn(async move {
-        while let Some(event) = rx.recv().await {
-            let dto_opt = match event {
-                ProcessingEvent::ScanStatus(status) => Some(ScanEventDto::Status(status.into())),
-                ProcessingEvent::TransactionsReady(e) => {
-                    Some(ScanEventDto::TransactionsReady(e.into()))
-                }
-                ProcessingEvent::TransactionsUpdated(e) => {
-                    Some(ScanEventDto::TransactionsUpdated(e.into()))
-                }
-                _ => None,
-            };
-
-            if let Some(dto) = dto_opt {
-                if stream_sink.add(dto).is_err() {
-                    loop_cancel_token.cancel();
-                    break;
-                }
-            }
-        }
-    });
-
-    let result = scan_future.await;
+    let result = scan_with_failover(&sink, &config, &db_path, &target, &cancel_token).await;
     {
         let mut guard = SCAN_TOKEN.write().map_err(|_| anyhow!("Failed to lock"))?;
         *guard = None;
@@ -225,7 +279,119 @@ pub async fn start_scan(sink: StreamSink<ScanEventDto>, config: ScanConfiguratio
         Ok(_) => Ok(()),
         Err(e) => {
             let _ = sink.add(ScanEventDto::Error(e.to_string()));
-            Err(anyhow!(e))
+            Err(e)
+        }
+    }
+}
+
+/// Runs the scanner against the first healthy node, moving on to the next one when it fails.
+///
+/// Only a node that is no longer healthy after the scan stopped counts as failed; other errors,
+/// such as a wrong password or a database problem, end the scan. The failover budget is reset
+/// whenever a node made progress, so a long continuous scan isn't ended by occasional outages.
+async fn scan_with_failover(
+    sink: &StreamSink<ScanEventDto>,
+    config: &ScanConfiguration,
+    db_path: &str,
+    target: &ScanTarget,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    let mut failed_url: Option<String> = None;
+    let mut failovers = 0;
+
+    loop {
+        let base_url = select_healthy_endpoint(
+            &target.candidates,
+            failed_url.as_deref(),
+            target.proxy.as_ref(),
+        )
+        .await?;
+        if let Some(failed_url) = failed_url.take() {
+            let _ = sink.add(ScanEventDto::NodeChanged {
+                from_url: failed_url,
+                to_url: base_url.clone(),
+            });
+        }
+
+        let mode = if config.continuous {
+            ScanMode::Continuous {
+                poll_interval: Duration::from_secs(config.poll_interval_seconds),
+            }
+        } else {
+            ScanMode::Full
+        };
+
+        // Lives until this node's scan ends.
+        let endpoint = proxied_endpoint(&base_url, target.proxy.as_ref()).await?;
+        let mut scanner_builder =
+            Scanner::new(&config.password, endpoint.url(), db_path, target.batch_size)
+                .mode(mode)
+                .cancel_token(cancel_token.clone());
+        if let Some(account_name) = &target.account_name {
+            scanner_builder = scanner_builder.account(account_name);
+        }
+
+        let (mut rx, scan_future) = scanner_builder.run_with_events();
+
+        let stream_sink = sink.clone();
+        let loop_cancel_token = cancel_token.clone();
+        let account_filter = config.account_id;
+        let progressed = Arc::new(AtomicBool::new(false));
+        let loop_progressed = progressed.clone();
+
+        tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                notify_balance_changed();
+                if let ProcessingEvent::ScanStatus(
+                    ScanStatusEvent::Progress { .. } | ScanStatusEvent::Completed { .. },
+                ) = &event
+                {
+                    loop_progressed.store(true, Ordering::Relaxed);
+                }
+                let dto_opt = match event {
+                    ProcessingEvent::ScanStatus(status) => {
+                        Some(ScanEventDto::Status(status.into()))
+                    }
+                    ProcessingEvent::TransactionsReady(e) => {
+                        Some(ScanEventDto::TransactionsReady(e.into()))
+                    }
+                    ProcessingEvent::TransactionsUpdated(e) => {
+                        Some(ScanEventDto::TransactionsUpdated(e.into()))
+                    }
+                    _ => None,
+                }
+                .filter(|dto| match (account_filter, dto.account_id()) {
+                    (Some(wanted), Some(account_id)) => wanted == account_id,
+                    _ => true,
+                });
+
+                let dto_opt = dto_opt.map(label_counterparties);
+
+                if let Some(dto) = dto_opt {
+                    if stream_sink.add(dto).is_err() {
+                        loop_cancel_token.cancel();
+                        break;
+                    }
+                }
+            }
+        });
+
+        let result = scan_future.await;
+        if progressed.load(Ordering::Relaxed) {
+            failovers = 0;
+        }
+        match result {
+            Ok(_) => return Ok(()),
+            Err(_) if cancel_token.is_cancelled() => return Ok(()),
+            Err(e) if failovers >= MAX_SCAN_FAILOVERS => return Err(anyhow!(e)),
+            Err(e) if is_node_healthy(&base_url, target.proxy.as_ref()).await => {
+                return Err(anyhow!(e))
+            }
+            Err(_) => {
+                tokio::time::sleep(backoff_delay(failovers)).await;
+                failovers += 1;
+                failed_url = Some(base_url);
+            }
         }
     }
 }
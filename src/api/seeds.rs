@@ -1,10 +1,262 @@
+use crate::api::wallet::{decode_seed, parse_passphrase};
+use anyhow::{Context, Result};
 use flutter_rust_bridge::frb;
-use tari_common_types::seeds::mnemonic_wordlists::MNEMONIC_ENGLISH_WORDS;
+use tari_common_types::seeds::error::KeyManagerError;
+use tari_common_types::seeds::mnemonic::MnemonicLanguage;
+use tari_common_types::seeds::mnemonic_wordlists::{
+    MNEMONIC_CHINESE_SIMPLIFIED_WORDS, MNEMONIC_ENGLISH_WORDS, MNEMONIC_FRENCH_WORDS,
+    MNEMONIC_ITALIAN_WORDS, MNEMONIC_JAPANESE_WORDS, MNEMONIC_KOREAN_WORDS, MNEMONIC_SPANISH_WORDS,
+};
+use tari_common_types::seeds::seed_words::SeedWords;
+
+const SEED_WORD_COUNT: usize = 24;
+const MAX_SUGGESTIONS: usize = 5;
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+#[frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedLanguage {
+    English,
+    ChineseSimplified,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Spanish,
+}
+
+const SEED_LANGUAGES: [SeedLanguage; 7] = [
+    SeedLanguage::English,
+    SeedLanguage::ChineseSimplified,
+    SeedLanguage::French,
+    SeedLanguage::Italian,
+    SeedLanguage::Japanese,
+    SeedLanguage::Korean,
+    SeedLanguage::Spanish,
+];
+
+impl SeedLanguage {
+    fn word_list(self) -> &'static [&'static str] {
+        match self {
+            SeedLanguage::English => &MNEMONIC_ENGLISH_WORDS,
+            SeedLanguage::ChineseSimplified => &MNEMONIC_CHINESE_SIMPLIFIED_WORDS,
+            SeedLanguage::French => &MNEMONIC_FRENCH_WORDS,
+            SeedLanguage::Italian => &MNEMONIC_ITALIAN_WORDS,
+            SeedLanguage::Japanese => &MNEMONIC_JAPANESE_WORDS,
+            SeedLanguage::Korean => &MNEMONIC_KOREAN_WORDS,
+            SeedLanguage::Spanish => &MNEMONIC_SPANISH_WORDS,
+        }
+    }
+}
+
+impl From<SeedLanguage> for MnemonicLanguage {
+    fn from(l: SeedLanguage) -> Self {
+        match l {
+            SeedLanguage::English => MnemonicLanguage::English,
+            SeedLanguage::ChineseSimplified => MnemonicLanguage::ChineseSimplified,
+            SeedLanguage::French => MnemonicLanguage::French,
+            SeedLanguage::Italian => MnemonicLanguage::Italian,
+            SeedLanguage::Japanese => MnemonicLanguage::Japanese,
+            SeedLanguage::Korean => MnemonicLanguage::Korean,
+            SeedLanguage::Spanish => MnemonicLanguage::Spanish,
+        }
+    }
+}
+
+#[frb]
+#[derive(Clone, Debug)]
+pub struct SeedWordValidationDto {
+    pub index: u32,
+    pub word: String,
+    pub is_valid: bool,
+    pub suggestions: Vec<String>,
+}
 
 #[frb]
-pub fn list_words() -> Vec<String> {
-    MNEMONIC_ENGLISH_WORDS
+#[derive(Clone, Debug)]
+pub struct SeedValidationDto {
+    pub language: SeedLanguage,
+    pub words: Vec<SeedWordValidationDto>,
+    pub unknown_word_indices: Vec<u32>,
+    pub has_expected_length: bool,
+    pub checksum_valid: bool,
+    pub decryption_succeeded: bool,
+    pub birthday: Option<u16>,
+    pub error: Option<String>,
+}
+
+#[frb]
+pub fn list_words(language: Option<SeedLanguage>) -> Vec<String> {
+    language
+        .unwrap_or(SeedLanguage::English)
+        .word_list()
         .iter()
         .map(|w| w.to_string())
         .collect()
 }
+
+/// Guesses the language of a seed phrase from the word list that knows most of its words.
+#[frb]
+pub fn detect_seed_language(words: Vec<String>) -> SeedLanguage {
+    detect_language(&words)
+}
+
+/// Checks a seed phrase word by word and then as a whole, so typos can be fixed before restoring.
+///
+/// The language is detected from the words unless given.
+#[frb]
+pub fn validate_seed_words(
+    words: Vec<String>,
+    passphrase: Option<String>,
+    language: Option<SeedLanguage>,
+) -> SeedValidationDto {
+    let words: Vec<String> = words.iter().map(|w| normalize_word(w)).collect();
+    let language = language.unwrap_or_else(|| detect_language(&words));
+    let word_list = language.word_list();
+
+    let word_results: Vec<SeedWordValidationDto> = words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            let is_valid = word_list.contains(&word.as_str());
+            SeedWordValidationDto {
+                index: index as u32,
+                word: word.clone(),
+                is_valid,
+                suggestions: if is_valid {
+                    Vec::new()
+                } else {
+                    suggest_words(word, word_list)
+                },
+            }
+        })
+        .collect();
+    let unknown_word_indices: Vec<u32> = word_results
+        .iter()
+        .filter(|w| !w.is_valid)
+        .map(|w| w.index)
+        .collect();
+    let has_expected_length = words.len() == SEED_WORD_COUNT;
+
+    let mut result = SeedValidationDto {
+        language,
+        words: word_results,
+        unknown_word_indices,
+        has_expected_length,
+        checksum_valid: false,
+        decryption_succeeded: false,
+        birthday: None,
+        error: None,
+    };
+
+    if !result.unknown_word_indices.is_empty() {
+        result.error = Some("Seed phrase contains unknown words".to_string());
+        return result;
+    }
+    if !has_expected_length {
+        result.error = Some(format!(
+            "Seed phrase must have {} words, got {}",
+            SEED_WORD_COUNT,
+            words.len()
+        ));
+        return result;
+    }
+
+    match parse_passphrase(passphrase).and_then(|password| decode_seed(&words, password)) {
+        Ok(seed) => {
+            result.checksum_valid = true;
+            result.decryption_succeeded = true;
+            result.birthday = Some(seed.birthday());
+        }
+        Err(e) => {
+            result.checksum_valid = matches!(
+                e.downcast_ref::<KeyManagerError>(),
+                Some(KeyManagerError::DecryptionFailed)
+            );
+            result.error = Some(format!("{:#}", e));
+        }
+    }
+
+    result
+}
+
+pub(crate) fn detect_language(words: &[String]) -> SeedLanguage {
+    // Lists overlap (e.g. English and French), so ties go to the first language in the list.
+    let words: Vec<String> = words.iter().map(|w| normalize_word(w)).collect();
+    SEED_LANGUAGES
+        .iter()
+        .copied()
+        .rev()
+        .max_by_key(|language| {
+            let word_list = language.word_list();
+            words
+                .iter()
+                .filter(|w| word_list.contains(&w.as_str()))
+                .count()
+        })
+        .unwrap_or(SeedLanguage::English)
+}
+
+pub(crate) fn normalize_word(word: &str) -> String {
+    word.trim().to_lowercase()
+}
+
+pub(crate) fn seed_words_to_vec(words: &SeedWords) -> Result<Vec<String>> {
+    (0..words.len())
+        .map(|i| {
+            words
+                .get_word(i)
+                .map(|w| w.to_string())
+                .context("Failed to read seed word")
+        })
+        .collect()
+}
+
+/// Words sharing the typed prefix first, then the closest words by edit distance.
+fn suggest_words(word: &str, word_list: &[&str]) -> Vec<String> {
+    if word.is_empty() {
+        return Vec::new();
+    }
+
+    let mut suggestions: Vec<String> = word_list
+        .iter()
+        .filter(|candidate| candidate.starts_with(word))
+        .take(MAX_SUGGESTIONS)
+        .map(|candidate| candidate.to_string())
+        .collect();
+
+    let mut by_distance: Vec<(usize, &str)> = word_list
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    by_distance.sort();
+
+    for (_, candidate) in by_distance {
+        if suggestions.len() >= MAX_SUGGESTIONS {
+            break;
+        }
+        if !suggestions.iter().any(|s| s == candidate) {
+            suggestions.push(candidate.to_string());
+        }
+    }
+
+    suggestions
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
@@ -1,6 +1,14 @@
-use crate::api::db::get_db_pool;
-use crate::api::network::parse_network;
+use crate::api::accounts::resolve_unique_account;
+use crate::api::balance::notify_balance_changed;
+use crate::api::contacts::{apply_contact_labels, resolve_recipient};
+use crate::api::db::{get_db_connection, get_db_pool};
+use crate::api::network::{ensure_address_network, resolve_wallet_network, NetworkError};
+use crate::api::network_profiles::resolve_network_profile;
+use crate::api::node::{node_candidates, select_healthy_endpoint};
+use crate::api::proxy::{proxied_endpoint, resolve_proxy, ProxySettingsDto};
 use crate::api::transactions::DisplayedTransactionDto;
+use crate::api::vault::{is_wallet_unlocked, unlocked_key_manager};
+use crate::api::wallet::{find_wallet_record, DEFAULT_WALLET_NAME};
 use crate::frb_generated::StreamSink;
 use anyhow::{anyhow, Result};
 use flutter_rust_bridge::frb;
@@ -21,23 +29,26 @@ use tari_transaction_components::MicroMinotari;
 use tari_utilities::SafePassword;
 use thiserror::Error;
 
-const DEFAULT_BASE_URL: &str = "https://rpc.tari.com";
 const DEFAULT_PASSPHRASE: &str = "";
-const DEFAULT_WALLET_NAME: &str = "default";
-const DEFAULT_CONFIRMATION_WINDOW: u64 = 3;
-const SECONDS_TO_LOCK_UTXO: u64 = 60 * 60 * 24; // 24 hrs
+pub(crate) const SECONDS_TO_LOCK_UTXO: u64 = 60 * 60 * 24; // 24 hrs
 
 #[frb]
 pub struct SendTransactionDetails {
-    pub seed_words: Vec<String>,
+    /// Seed words to sign with; when omitted the wallet's unlocked vault session is used.
+    pub seed_words: Option<Vec<String>>,
     pub passphrase: Option<String>,
     pub network: Option<String>,
     pub base_url: Option<String>,
     pub wallet_name: Option<String>,
+    /// Account to spend from; the wallet's funds are used when `None`. The wallet backend opens
+    /// accounts by name, so an account that shares its name with another can't be picked here.
+    pub account_id: Option<i64>,
     pub recipient_address: String,
     pub amount: u64,
     pub payment_id: Option<String>,
     pub confirmation_window: Option<u64>,
+    /// Overrides the global proxy for this transaction.
+    pub proxy: Option<ProxySettingsDto>,
 }
 
 #[frb]
@@ -78,12 +89,21 @@ pub enum TransactionError {
     #[error("Network Error: {0}")]
     NetworkError(String),
 
+    #[error("Network Mismatch: {0}")]
+    NetworkMismatch(String),
+
     #[error("Database Error: {0}")]
     DatabaseError(String),
 
     #[error("Signing Error: {0}")]
     SigningError(String),
 
+    #[error("Wallet '{0}' is locked")]
+    WalletLocked(String),
+
+    #[error("Wallet '{0}' is watch-only and cannot sign transactions")]
+    WatchOnlyWallet(String),
+
     #[error("Aborted by User")]
     Aborted,
 }
@@ -107,6 +127,7 @@ pub async fn send_transaction(
     )
     .await?;
     let validated = validate_inputs(&details)?;
+    let wallet_name = validated.wallet_name.clone();
 
     report_status(
         &sink,
@@ -114,8 +135,23 @@ pub async fn send_transaction(
         "Accessing wallet database...",
     )
     .await?;
-    let mut sender =
-        create_transaction_sender(&details, validated.network, validated.confirmations)?;
+    ensure_wallet_can_sign(&wallet_name)?;
+    if details.seed_words.is_none() && !is_wallet_unlocked(Some(wallet_name.clone()))? {
+        return Err(TransactionError::WalletLocked(wallet_name).into());
+    }
+    let proxy = resolve_proxy(details.proxy.as_ref())
+        .map_err(|e| TransactionError::NetworkError(e.to_string()))?;
+    // Picked before signing so an unreachable network fails fast. The broadcast itself is not
+    // retried elsewhere: finalizing also records the transaction in the wallet database.
+    let base_url = select_healthy_endpoint(&validated.node_candidates, None, proxy.as_ref())
+        .await
+        .map_err(|e| TransactionError::NetworkError(e.to_string()))?;
+    let mut sender = create_transaction_sender(
+        details.passphrase.as_deref(),
+        &wallet_name,
+        validated.network,
+        validated.confirmations,
+    )?;
 
     report_status(
         &sink,
@@ -138,7 +174,11 @@ pub async fn send_transaction(
     .await?;
 
     let signed_transaction = {
-        let key_manager = derive_key_manager(&details.seed_words, details.passphrase.as_deref())?;
+        let key_manager = match &details.seed_words {
+            Some(seed_words) => derive_key_manager(seed_words, details.passphrase.as_deref())?,
+            None => unlocked_key_manager(&wallet_name)
+                .map_err(|_| TransactionError::WalletLocked(wallet_name.clone()))?,
+        };
 
         report_status(
             &sink,
@@ -165,31 +205,67 @@ pub async fn send_transaction(
     )
     .await?;
 
-    let base_url = details.base_url.unwrap_or(DEFAULT_BASE_URL.to_string());
-
-    let result_tx = sender
-        .finalize_transaction_and_broadcast(signed_transaction, base_url)
+    let endpoint = proxied_endpoint(&base_url, proxy.as_ref())
         .await
         .map_err(|e| TransactionError::NetworkError(e.to_string()))?;
+    let result_tx = sender
+        .finalize_transaction_and_broadcast(signed_transaction, endpoint.url().to_string())
+        .await;
+    notify_balance_changed();
+    let result_tx = result_tx.map_err(|e| TransactionError::NetworkError(e.to_string()))?;
 
     report_status(&sink, TransactionStage::Completed, "Transaction sent").await?;
 
-    Ok(result_tx.into())
+    let mut result_tx: DisplayedTransactionDto = result_tx.into();
+    if let Ok(conn) = get_db_connection() {
+        let _ = apply_contact_labels(&conn, std::slice::from_mut(&mut result_tx));
+    }
+
+    Ok(result_tx)
 }
 
-struct ValidatedInputs {
-    network: Network,
-    recipient_address: TariAddress,
-    amount: MicroMinotari,
-    confirmations: u64,
+#[frb(ignore)]
+pub(crate) struct ValidatedInputs {
+    pub wallet_name: String,
+    pub network: Network,
+    pub node_candidates: Vec<String>,
+    pub recipient_address: TariAddress,
+    pub amount: MicroMinotari,
+    pub confirmations: u64,
 }
 
-fn validate_inputs(details: &SendTransactionDetails) -> Result<ValidatedInputs> {
-    let network = parse_network(details.network.clone())
-        .map_err(|e| TransactionError::NetworkError(e.to_string()))?;
+pub(crate) fn validate_inputs(details: &SendTransactionDetails) -> Result<ValidatedInputs> {
+    let mut conn =
+        get_db_connection().map_err(|e| TransactionError::DatabaseError(e.to_string()))?;
+    // An account id alone is enough to find the wallet; without one the default wallet is used.
+    let wallet_name = match details.account_id {
+        Some(account_id) => {
+            resolve_unique_account(&mut conn, details.wallet_name.as_deref(), Some(account_id))
+                .map_err(|e| TransactionError::WalletError(e.to_string()))?
+                .friendly_name
+        }
+        None => details
+            .wallet_name
+            .clone()
+            .unwrap_or(DEFAULT_WALLET_NAME.to_string()),
+    };
+    let network =
+        resolve_wallet_network(&conn, &wallet_name, details.network.clone()).map_err(|e| {
+            match e.downcast_ref::<NetworkError>() {
+                Some(_) => TransactionError::NetworkMismatch(e.to_string()),
+                None => TransactionError::NetworkError(e.to_string()),
+            }
+        })?;
 
-    let recipient_address = TariAddress::from_base58(&details.recipient_address)
+    let recipient_address = resolve_recipient(&details.recipient_address)
         .map_err(|e| TransactionError::InvalidAddress(e.to_string()))?;
+    ensure_address_network(&wallet_name, network, &recipient_address)
+        .map_err(|e| TransactionError::NetworkMismatch(e.to_string()))?;
+
+    let profile = resolve_network_profile(&conn, network)
+        .map_err(|e| TransactionError::DatabaseError(e.to_string()))?;
+    let node_candidates = node_candidates(&conn, network, details.base_url.clone())
+        .map_err(|e| TransactionError::NetworkError(e.to_string()))?;
 
     if details.amount == 0 {
         return Err(anyhow!(TransactionError::WalletError(
@@ -198,37 +274,49 @@ fn validate_inputs(details: &SendTransactionDetails) -> Result<ValidatedInputs>
     }
 
     Ok(ValidatedInputs {
+        wallet_name,
         network,
+        node_candidates,
         recipient_address,
         amount: MicroMinotari(details.amount),
         confirmations: details
             .confirmation_window
-            .unwrap_or(DEFAULT_CONFIRMATION_WINDOW),
+            .unwrap_or(profile.confirmation_window),
     })
 }
 
-fn create_transaction_sender(
-    details: &SendTransactionDetails,
+fn ensure_wallet_can_sign(wallet_name: &str) -> Result<()> {
+    let conn = get_db_connection().map_err(|e| TransactionError::DatabaseError(e.to_string()))?;
+    let record = find_wallet_record(&conn, wallet_name)
+        .map_err(|e| TransactionError::DatabaseError(e.to_string()))?;
+    if record.is_some_and(|r| r.is_watch_only) {
+        return Err(TransactionError::WatchOnlyWallet(wallet_name.to_string()).into());
+    }
+
+    Ok(())
+}
+
+pub(crate) fn create_transaction_sender(
+    passphrase: Option<&str>,
+    wallet_name: &str,
     network: Network,
     confirmations: u64,
 ) -> Result<TransactionSender> {
     let db_pool = get_db_pool().map_err(|e| TransactionError::DatabaseError(e.to_string()))?;
 
-    let password = details
-        .passphrase
-        .clone()
-        .unwrap_or(DEFAULT_PASSPHRASE.to_string());
+    let password = passphrase.unwrap_or(DEFAULT_PASSPHRASE).to_string();
 
-    let wallet_name = details
-        .wallet_name
-        .clone()
-        .unwrap_or(DEFAULT_WALLET_NAME.to_string());
-
-    TransactionSender::new(db_pool, wallet_name, password, network, confirmations)
-        .map_err(|e| TransactionError::WalletError(e.to_string()).into())
+    TransactionSender::new(
+        db_pool,
+        wallet_name.to_string(),
+        password,
+        network,
+        confirmations,
+    )
+    .map_err(|e| TransactionError::WalletError(e.to_string()).into())
 }
 
-fn build_unsigned_transaction(
+pub(crate) fn build_unsigned_transaction(
     sender: &mut TransactionSender,
     address: TariAddress,
     amount: MicroMinotari,
@@ -247,11 +335,15 @@ fn build_unsigned_transaction(
         .map_err(|e| {
             TransactionError::WalletError(format!("Failed to build transaction: {}", e))
         })?;
+    notify_balance_changed();
 
     Ok(tx)
 }
 
-fn derive_key_manager(seed_words: &[String], passphrase: Option<&str>) -> Result<KeyManager> {
+pub(crate) fn derive_key_manager(
+    seed_words: &[String],
+    passphrase: Option<&str>,
+) -> Result<KeyManager> {
     let seed_str = seed_words.join(" ");
     let mnemonic = SeedWords::from_str(&seed_str)
         .map_err(|e| TransactionError::InvalidSeedWords(e.to_string()))?;
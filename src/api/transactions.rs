@@ -1,9 +1,12 @@
-use crate::api::db::get_db_connection;
-use anyhow::{Context, Result};
-use flutter_rust_bridge::frb;
-use minotari_wallet::{
-    db::get_displayed_transactions_paginated, get_accounts, utils::format_timestamp,
+use crate::api::{
+    accounts::resolve_account,
+    contacts::apply_contact_labels,
+    db::get_db_connection,
+    integrated_addresses::{apply_integrated_addresses, IntegratedAddressDto},
 };
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use minotari_wallet::{db::get_displayed_transactions_paginated, utils::format_timestamp};
 
 #[frb]
 #[derive(Clone)]
@@ -70,6 +73,8 @@ pub struct DisplayedTransactionDto {
     pub counterparty: Option<CounterpartyInfoDto>,
     pub blockchain: BlockchainInfoDto,
     pub fee: Option<FeeInfoDto>,
+    /// The integrated address this incoming payment was sent to, if it is one of ours.
+    pub integrated_address: Option<IntegratedAddressDto>,
 }
 
 impl From<minotari_wallet::DisplayedTransaction> for DisplayedTransactionDto {
@@ -85,6 +90,7 @@ impl From<minotari_wallet::DisplayedTransaction> for DisplayedTransactionDto {
             counterparty: t.counterparty.map(CounterpartyInfoDto::from),
             blockchain: t.blockchain.into(),
             fee: t.fee.map(FeeInfoDto::from),
+            integrated_address: None,
         }
     }
 }
@@ -92,16 +98,18 @@ impl From<minotari_wallet::DisplayedTransaction> for DisplayedTransactionDto {
 #[frb]
 pub fn get_transactions(
     wallet_name: Option<String>,
+    account_id: Option<i64>,
     limit: i64,
     offset: i64,
 ) -> Result<Vec<DisplayedTransactionDto>> {
     let mut conn = get_db_connection()?;
-    let accounts = &get_accounts(&mut conn, wallet_name.as_deref())?;
-    let account = accounts
-        .first()
-        .context("No accounts found for this wallet")?;
+    let account = resolve_account(&mut conn, wallet_name.as_deref(), account_id)?;
 
     let transactions = get_displayed_transactions_paginated(&conn, account.id, limit, offset)?;
+    let mut transactions: Vec<DisplayedTransactionDto> =
+        transactions.into_iter().map(Into::into).collect();
+    apply_contact_labels(&conn, &mut transactions)?;
+    apply_integrated_addresses(&conn, account.id, &mut transactions)?;
 
-    Ok(transactions.into_iter().map(Into::into).collect())
+    Ok(transactions)
 }
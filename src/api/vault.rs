@@ -0,0 +1,178 @@
+use crate::api::db::get_db_connection;
+use crate::api::wallet::{
+    decode_seed, get_wallet_record, key_manager_from_seed, parse_passphrase,
+    verify_seed_matches_wallet, DEFAULT_WALLET_NAME,
+};
+use anyhow::{anyhow, Context, Result};
+use flutter_rust_bridge::frb;
+use r2d2_sqlite::rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+use tari_common_types::seeds::cipher_seed::CipherSeed;
+use tari_transaction_components::key_manager::KeyManager;
+use tari_utilities::SafePassword;
+
+const DEFAULT_AUTO_LOCK_SECONDS: u64 = 5 * 60;
+
+#[frb(ignore)]
+struct UnlockedSeed {
+    seed: CipherSeed,
+    /// Wall-clock time, so that time spent with the device suspended counts towards the timeout.
+    expires_at: SystemTime,
+}
+
+static UNLOCKED_SEEDS: RwLock<Option<HashMap<String, UnlockedSeed>>> = RwLock::new(None);
+
+/// Stores the wallet's seed in the database, enciphered under a key derived from `pin`.
+///
+/// The seed words are checked against the wallet before anything is written.
+#[frb]
+pub fn store_seed_in_vault(
+    wallet_name: Option<String>,
+    seed_words: Vec<String>,
+    passphrase: Option<String>,
+    pin: String,
+) -> Result<()> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let seed = decode_seed(&seed_words, parse_passphrase(passphrase)?)?;
+
+    let conn = get_db_connection()?;
+    let record = get_wallet_record(&conn, &wallet_name)?;
+    verify_seed_matches_wallet(seed.clone(), &record)?;
+
+    let enciphered = seed
+        .encipher(Some(parse_pin(&pin)?))
+        .map_err(|e| anyhow!("Failed to encipher seed: {}", e))?;
+
+    conn.execute(
+        "INSERT OR REPLACE INTO cw_seed_vault (wallet_name, enciphered_seed) VALUES (?1, ?2)",
+        params![wallet_name, enciphered],
+    )
+    .context("Failed to store seed")?;
+    forget_unlocked_seed(&wallet_name)?;
+
+    Ok(())
+}
+
+#[frb]
+pub fn remove_seed_from_vault(wallet_name: Option<String>) -> Result<()> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let conn = get_db_connection()?;
+    conn.execute(
+        "DELETE FROM cw_seed_vault WHERE wallet_name = ?1",
+        params![wallet_name],
+    )
+    .context("Failed to remove seed")?;
+    forget_unlocked_seed(&wallet_name)?;
+
+    Ok(())
+}
+
+#[frb]
+pub fn has_vault_seed(wallet_name: Option<String>) -> Result<bool> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let conn = get_db_connection()?;
+    Ok(load_enciphered_seed(&conn, &wallet_name)?.is_some())
+}
+
+/// Decrypts the vault seed and keeps it in memory until `lock_wallet` is called or
+/// `auto_lock_seconds` (five minutes by default) have passed.
+#[frb]
+pub fn unlock_wallet(
+    wallet_name: Option<String>,
+    pin: String,
+    auto_lock_seconds: Option<u64>,
+) -> Result<()> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let conn = get_db_connection()?;
+    let enciphered = load_enciphered_seed(&conn, &wallet_name)?
+        .with_context(|| format!("No seed stored for wallet '{}'", wallet_name))?;
+
+    let seed = CipherSeed::from_enciphered_bytes(&enciphered, Some(parse_pin(&pin)?))
+        .map_err(|_| anyhow!("Incorrect PIN"))?;
+
+    let auto_lock_seconds = auto_lock_seconds.unwrap_or(DEFAULT_AUTO_LOCK_SECONDS);
+    let expires_at = SystemTime::now()
+        .checked_add(Duration::from_secs(auto_lock_seconds))
+        .with_context(|| format!("Auto-lock timeout of {}s is too long", auto_lock_seconds))?;
+    let mut guard = UNLOCKED_SEEDS
+        .write()
+        .map_err(|_| anyhow!("Failed to lock UNLOCKED_SEEDS for writing"))?;
+    guard
+        .get_or_insert_with(HashMap::new)
+        .insert(wallet_name, UnlockedSeed { seed, expires_at });
+
+    Ok(())
+}
+
+#[frb]
+pub fn lock_wallet(wallet_name: Option<String>) -> Result<()> {
+    forget_unlocked_seed(&wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string()))
+}
+
+#[frb]
+pub fn lock_all_wallets() -> Result<()> {
+    let mut guard = UNLOCKED_SEEDS
+        .write()
+        .map_err(|_| anyhow!("Failed to lock UNLOCKED_SEEDS for writing"))?;
+    *guard = None;
+
+    Ok(())
+}
+
+#[frb]
+pub fn is_wallet_unlocked(wallet_name: Option<String>) -> Result<bool> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    Ok(unlocked_seed(&wallet_name)?.is_some())
+}
+
+/// Builds a key manager from the unlocked session of `wallet_name`.
+pub(crate) fn unlocked_key_manager(wallet_name: &str) -> Result<KeyManager> {
+    let seed = unlocked_seed(wallet_name)?
+        .with_context(|| format!("Wallet '{}' is locked", wallet_name))?;
+    key_manager_from_seed(seed)
+}
+
+pub(crate) fn forget_unlocked_seed(wallet_name: &str) -> Result<()> {
+    let mut guard = UNLOCKED_SEEDS
+        .write()
+        .map_err(|_| anyhow!("Failed to lock UNLOCKED_SEEDS for writing"))?;
+    if let Some(seeds) = guard.as_mut() {
+        seeds.remove(wallet_name);
+    }
+
+    Ok(())
+}
+
+fn unlocked_seed(wallet_name: &str) -> Result<Option<CipherSeed>> {
+    let mut guard = UNLOCKED_SEEDS
+        .write()
+        .map_err(|_| anyhow!("Failed to lock UNLOCKED_SEEDS for writing"))?;
+    let Some(seeds) = guard.as_mut() else {
+        return Ok(None);
+    };
+
+    let now = SystemTime::now();
+    seeds.retain(|_, unlocked| unlocked.expires_at > now);
+
+    Ok(seeds.get(wallet_name).map(|unlocked| unlocked.seed.clone()))
+}
+
+fn load_enciphered_seed(conn: &Connection, wallet_name: &str) -> Result<Option<Vec<u8>>> {
+    conn.query_row(
+        "SELECT enciphered_seed FROM cw_seed_vault WHERE wallet_name = ?1",
+        params![wallet_name],
+        |row| row.get(0),
+    )
+    .optional()
+    .context("Failed to read seed vault")
+}
+
+fn parse_pin(pin: &str) -> Result<SafePassword> {
+    if pin.is_empty() {
+        return Err(anyhow!("PIN must not be empty"));
+    }
+    SafePassword::from_str(pin).map_err(|_| anyhow!("Invalid PIN"))
+}
@@ -1,67 +1,483 @@
-use crate::api::db::get_db_path;
-use crate::api::network::parse_network;
-use anyhow::{anyhow, Context, Result};
+use crate::api::db::{get_db_connection, get_db_path};
+use crate::api::network::{birthday_to_height, parse_network, timestamp_to_birthday};
+use crate::api::node::{block_timestamp, node_candidates};
+use crate::api::proxy::resolve_proxy;
+use crate::api::seeds::{detect_language, normalize_word, seed_words_to_vec, SeedLanguage};
+use crate::api::vault::forget_unlocked_seed;
+use anyhow::{anyhow, bail, Context, Result};
 use flutter_rust_bridge::frb;
-use minotari_wallet::init_with_view_key;
+use minotari_wallet::{get_accounts, init_with_view_key};
+use r2d2_sqlite::rusqlite::{self, params, types::Type, Connection, OptionalExtension, Row};
 use std::str::FromStr;
+use std::sync::Mutex;
 use tari_common::configuration::Network;
 use tari_common_types::seeds::cipher_seed::CipherSeed;
 use tari_common_types::seeds::mnemonic::Mnemonic;
 use tari_common_types::seeds::seed_words::SeedWords;
 use tari_common_types::tari_address::{TariAddress, TariAddressFeatures};
+use tari_common_types::types::{CompressedPublicKey, PrivateKey};
 use tari_crypto::compressed_key::CompressedKey;
 use tari_transaction_components::key_manager::wallet_types::{SeedWordsWallet, WalletType};
 use tari_transaction_components::key_manager::{KeyManager, TransactionKeyManagerInterface};
 use tari_utilities::hex::Hex;
 use tari_utilities::SafePassword;
 
+pub(crate) const DEFAULT_WALLET_NAME: &str = "default";
+
+// Tables of this crate keyed by wallet name.
+const WALLET_NAME_TABLES: &[&str] = &["cw_wallets", "cw_seed_vault", "cw_integrated_addresses"];
+// minotari tables holding per-account rows. Tables an older schema doesn't have yet are skipped.
+const UPSTREAM_ACCOUNT_TABLES: &[&str] = &[
+    "inputs",
+    "outputs",
+    "balance_changes",
+    "pending_transactions",
+    "completed_transactions",
+    "displayed_transactions",
+    "scanned_tip_blocks",
+];
+
+static WALLET_INIT_LOCK: Mutex<()> = Mutex::new(());
+
 #[frb]
 pub struct WalletCreationDetails {
     pub tari_address: String,
     pub wallet_birthday: u16,
     pub spend_public_key_hex: String,
     pub view_private_key_hex: String,
+    pub seed_words: Vec<String>,
+    pub seed_language: SeedLanguage,
+    /// Estimated first block the scanner reads, or 0 when no base node could be asked for the
+    /// genesis time. The scanner derives its start from the birthday and may begin earlier.
+    pub estimated_scan_start_height: u64,
 }
 
 #[frb]
-pub fn create_wallet(network: Option<String>) -> Result<WalletCreationDetails> {
+#[derive(Clone, Debug)]
+pub struct WalletInfoDto {
+    pub wallet_name: String,
+    pub network: String,
+    pub wallet_birthday: u16,
+    /// See [`WalletCreationDetails::estimated_scan_start_height`].
+    pub estimated_scan_start_height: u64,
+    pub is_backed_up: bool,
+    pub is_watch_only: bool,
+}
+
+impl From<WalletRecord> for WalletInfoDto {
+    fn from(r: WalletRecord) -> Self {
+        Self {
+            wallet_name: r.wallet_name,
+            network: r.network.to_string(),
+            wallet_birthday: r.birthday,
+            estimated_scan_start_height: r.estimated_scan_start_height,
+            is_backed_up: r.is_backed_up,
+            is_watch_only: r.is_watch_only,
+        }
+    }
+}
+
+#[frb]
+pub async fn create_wallet(
+    wallet_name: Option<String>,
+    network: Option<String>,
+    passphrase: Option<String>,
+    language: Option<SeedLanguage>,
+) -> Result<WalletCreationDetails> {
+    let wallet_name = validate_wallet_name(wallet_name)?;
     let network = parse_network(network)?;
+    let password = parse_passphrase(passphrase)?;
+    let language = language.unwrap_or(SeedLanguage::English);
     let seed = CipherSeed::random();
 
-    let details = generate_details_from_seed(seed, network)?;
-    initialize_wallet(&details)?;
+    let mut details = generate_details_from_seed(seed, password, network, language)?;
+    details.estimated_scan_start_height =
+        estimate_scan_start_height(network, details.wallet_birthday).await;
+    initialize_wallet(
+        &wallet_name,
+        &details,
+        network,
+        details.wallet_birthday,
+        WalletOrigin::Created,
+    )?;
 
     Ok(details)
 }
 
+/// Restores a wallet from its seed words.
+///
+/// `start_height` overrides the block scanning starts from. It is mapped to a birthday through
+/// the block's timestamp, so a base node for the network must be reachable.
 #[frb]
-pub fn restore_wallet(
+pub async fn restore_wallet(
+    wallet_name: Option<String>,
     seed_words: Vec<String>,
     passphrase: Option<String>,
     network: Option<String>,
+    start_height: Option<u64>,
 ) -> Result<WalletCreationDetails> {
+    let wallet_name = validate_wallet_name(wallet_name)?;
     let network = parse_network(network)?;
-    let mnemonic = SeedWords::from_str(&seed_words.join(" ")).context("Invalid seed words")?;
-    let password = passphrase
-        .map(|p| SafePassword::from_str(&p))
-        .transpose()
-        .map_err(|_| anyhow!("Invalid password"))?;
-
-    let seed = CipherSeed::from_mnemonic(&mnemonic, password).context("Invalid cipher seed")?;
+    let password = parse_passphrase(passphrase)?;
+    let seed = decode_seed(&seed_words, password.clone())?;
+    let language = detect_language(&seed_words);
 
-    let details = generate_details_from_seed(seed, network)?;
-    initialize_wallet(&details)?;
+    let mut details = generate_details_from_seed(seed, password, network, language)?;
+    // The scanner derives its starting block from the birthday, so an explicit start height is
+    // handed over as a birthday that starts scanning no later than that height.
+    let scanner_birthday = match start_height {
+        Some(start_height) => {
+            let timestamp = block_time(network, start_height)
+                .await
+                .context("A start height can only be used while a base node is reachable")?;
+            details.estimated_scan_start_height = start_height;
+            timestamp_to_birthday(timestamp)?
+        }
+        None => {
+            details.estimated_scan_start_height =
+                estimate_scan_start_height(network, details.wallet_birthday).await;
+            details.wallet_birthday
+        }
+    };
+    initialize_wallet(
+        &wallet_name,
+        &details,
+        network,
+        scanner_birthday,
+        WalletOrigin::Restored,
+    )?;
 
     Ok(details)
 }
 
-fn generate_details_from_seed(seed: CipherSeed, network: Network) -> Result<WalletCreationDetails> {
-    let wallet_birthday = seed.birthday();
+/// Imports a wallet that can follow incoming funds but never sign, returning its Tari address.
+#[frb]
+pub async fn import_view_only_wallet(
+    view_key_hex: String,
+    spend_pub_hex: String,
+    birthday: Option<u16>,
+    network: Option<String>,
+    wallet_name: Option<String>,
+) -> Result<String> {
+    let wallet_name = validate_wallet_name(wallet_name)?;
+    let network = parse_network(network)?;
+
+    let view_key = PrivateKey::from_hex(view_key_hex.trim())
+        .map_err(|e| anyhow!("Invalid view private key: {}", e))?;
+    let spend_key = CompressedPublicKey::from_hex(spend_pub_hex.trim())
+        .map_err(|e| anyhow!("Invalid spend public key: {}", e))?;
+
+    let tari_address = TariAddress::new_dual_address(
+        CompressedKey::from_secret_key(&view_key),
+        spend_key.clone(),
+        network,
+        TariAddressFeatures::create_one_sided_only(),
+        None,
+    )
+    .context("Failed to generate Tari address")?;
+
+    let wallet_birthday = birthday.unwrap_or(0);
+    let details = WalletCreationDetails {
+        tari_address: tari_address.to_base58(),
+        wallet_birthday,
+        spend_public_key_hex: spend_key.to_hex(),
+        view_private_key_hex: view_key.to_hex(),
+        seed_words: Vec::new(),
+        seed_language: SeedLanguage::English,
+        estimated_scan_start_height: estimate_scan_start_height(network, wallet_birthday).await,
+    };
+    initialize_wallet(
+        &wallet_name,
+        &details,
+        network,
+        wallet_birthday,
+        WalletOrigin::ViewOnly,
+    )?;
+
+    Ok(details.tari_address)
+}
+
+/// Checks the seed words the user wrote down against the stored wallet keys and, if they match,
+/// marks the wallet as backed up.
+#[frb]
+pub fn confirm_seed_backup(
+    wallet_name: Option<String>,
+    seed_words: Vec<String>,
+    passphrase: Option<String>,
+) -> Result<()> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let password = parse_passphrase(passphrase)?;
+    let seed = decode_seed(&seed_words, password)?;
+
+    let conn = get_db_connection()?;
+    let record = get_wallet_record(&conn, &wallet_name)?;
+    verify_seed_matches_wallet(seed, &record)?;
+
+    conn.execute(
+        "UPDATE cw_wallets SET is_backed_up = 1 WHERE wallet_name = ?1",
+        params![wallet_name],
+    )
+    .context("Failed to mark wallet as backed up")?;
+
+    Ok(())
+}
+
+/// Re-enciphers the wallet's seed under `new_passphrase` and returns the new seed words.
+///
+/// The seed itself is unchanged, so the wallet keeps its keys, address and scan history; only the
+/// words the user has to keep change.
+#[frb]
+pub fn change_seed_passphrase(
+    wallet_name: Option<String>,
+    seed_words: Vec<String>,
+    old_passphrase: Option<String>,
+    new_passphrase: Option<String>,
+) -> Result<Vec<String>> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let seed = decode_seed(&seed_words, parse_passphrase(old_passphrase)?)?;
+
+    let conn = get_db_connection()?;
+    let record = get_wallet_record(&conn, &wallet_name)?;
+    verify_seed_matches_wallet(seed.clone(), &record)?;
+
+    let mnemonic = seed
+        .to_mnemonic(
+            detect_language(&seed_words).into(),
+            parse_passphrase(new_passphrase)?,
+        )
+        .context("Failed to encode seed words")?;
+
+    seed_words_to_vec(&mnemonic)
+}
+
+#[frb]
+pub fn list_wallets() -> Result<Vec<WalletInfoDto>> {
+    let conn = get_db_connection()?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM cw_wallets ORDER BY created_at, wallet_name",
+        WALLET_RECORD_COLUMNS
+    ))?;
+    let wallets = stmt
+        .query_map([], wallet_record_from_row)?
+        .map(|r| r.map(Into::into))
+        .collect::<rusqlite::Result<Vec<WalletInfoDto>>>()
+        .context("Failed to list wallets")?;
+
+    Ok(wallets)
+}
+
+#[frb]
+pub fn rename_wallet(wallet_name: String, new_wallet_name: String) -> Result<()> {
+    let new_wallet_name = validate_wallet_name(Some(new_wallet_name))?;
+    let _guard = WALLET_INIT_LOCK
+        .lock()
+        .map_err(|_| anyhow!("Wallet initialization lock poisoned"))?;
+    let mut conn = get_db_connection()?;
+    get_wallet_record(&conn, &wallet_name)?;
+    ensure_wallet_name_available(&mut conn, &new_wallet_name)?;
+
+    let tx = conn.transaction()?;
+    tx.execute(
+        "UPDATE accounts SET friendly_name = ?2 WHERE friendly_name = ?1",
+        params![wallet_name, new_wallet_name],
+    )?;
+    for table in WALLET_NAME_TABLES {
+        tx.execute(
+            &format!(
+                "UPDATE {} SET wallet_name = ?2 WHERE wallet_name = ?1",
+                table
+            ),
+            params![wallet_name, new_wallet_name],
+        )?;
+    }
+    tx.commit().context("Failed to rename wallet")?;
+    forget_unlocked_seed(&wallet_name)?;
+
+    Ok(())
+}
+
+/// Removes the wallet's accounts together with every output, transaction and scan state row that
+/// references them.
+#[frb]
+pub fn delete_wallet(wallet_name: String) -> Result<()> {
+    let mut conn = get_db_connection()?;
+    get_wallet_record(&conn, &wallet_name)?;
+    let account_ids: Vec<i64> = get_accounts(&mut conn, Some(&wallet_name))?
+        .iter()
+        .map(|a| a.id)
+        .collect();
+
+    let tx = conn.transaction()?;
+    // Child tables reference each other as well as the account, so let the deletes settle before
+    // foreign keys are checked. A table missing from the lists below still references the account
+    // and fails the commit instead of being left behind or cleared blindly.
+    tx.execute_batch("PRAGMA defer_foreign_keys = ON;")?;
+    let account_tables = existing_tables(&tx, UPSTREAM_ACCOUNT_TABLES)?;
+    for account_id in account_ids {
+        for table in &account_tables {
+            tx.execute(
+                &format!("DELETE FROM {} WHERE account_id = ?1", table),
+                params![account_id],
+            )?;
+        }
+        tx.execute("DELETE FROM accounts WHERE id = ?1", params![account_id])?;
+    }
+    for table in WALLET_NAME_TABLES {
+        tx.execute(
+            &format!("DELETE FROM {} WHERE wallet_name = ?1", table),
+            params![wallet_name],
+        )?;
+    }
+    tx.commit().context("Failed to delete wallet")?;
+    forget_unlocked_seed(&wallet_name)?;
+
+    Ok(())
+}
+
+#[frb(ignore)]
+pub(crate) struct WalletRecord {
+    pub wallet_name: String,
+    pub network: Network,
+    pub birthday: u16,
+    pub spend_public_key_hex: String,
+    pub is_backed_up: bool,
+    pub estimated_scan_start_height: u64,
+    pub is_watch_only: bool,
+}
+
+const WALLET_RECORD_COLUMNS: &str = "wallet_name, network, birthday, spend_public_key_hex, \
+    is_backed_up, scan_start_height, is_watch_only";
+
+fn wallet_record_from_row(row: &Row) -> rusqlite::Result<WalletRecord> {
+    let network: String = row.get(1)?;
+    Ok(WalletRecord {
+        wallet_name: row.get(0)?,
+        network: Network::from_str(&network)
+            .map_err(|e| rusqlite::Error::FromSqlConversionFailure(1, Type::Text, Box::new(e)))?,
+        birthday: row.get(2)?,
+        spend_public_key_hex: row.get(3)?,
+        is_backed_up: row.get(4)?,
+        estimated_scan_start_height: row.get(5)?,
+        is_watch_only: row.get(6)?,
+    })
+}
+
+pub(crate) fn find_wallet_record(
+    conn: &Connection,
+    wallet_name: &str,
+) -> Result<Option<WalletRecord>> {
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM cw_wallets WHERE wallet_name = ?1",
+            WALLET_RECORD_COLUMNS
+        ),
+        params![wallet_name],
+        wallet_record_from_row,
+    )
+    .optional()
+    .context("Failed to read wallet metadata")
+}
+
+pub(crate) fn get_wallet_record(conn: &Connection, wallet_name: &str) -> Result<WalletRecord> {
+    find_wallet_record(conn, wallet_name)?
+        .with_context(|| format!("Wallet '{}' not found", wallet_name))
+}
+
+/// Fails unless `seed` derives the same spend key as the stored wallet.
+pub(crate) fn verify_seed_matches_wallet(seed: CipherSeed, record: &WalletRecord) -> Result<()> {
+    let details = generate_details_from_seed(seed, None, record.network, SeedLanguage::English)?;
+    if details.spend_public_key_hex != record.spend_public_key_hex {
+        bail!(
+            "Seed words do not belong to wallet '{}'",
+            record.wallet_name
+        );
+    }
+    Ok(())
+}
+
+fn validate_wallet_name(wallet_name: Option<String>) -> Result<String> {
+    let wallet_name = wallet_name.unwrap_or(DEFAULT_WALLET_NAME.to_string());
+    let wallet_name = wallet_name.trim();
+    if wallet_name.is_empty() {
+        bail!("Wallet name must not be empty");
+    }
+    Ok(wallet_name.to_string())
+}
+
+fn ensure_wallet_name_available(conn: &mut Connection, wallet_name: &str) -> Result<()> {
+    let has_accounts = !get_accounts(conn, Some(wallet_name))?.is_empty();
+    if has_accounts {
+        bail!("A wallet named '{}' already exists", wallet_name);
+    }
+    // Metadata is written before the scanner account, so a row without an account is what an
+    // interrupted initialization leaves behind and is safe to replace.
+    if find_wallet_record(conn, wallet_name)?.is_some() {
+        remove_wallet_metadata(conn, wallet_name)?;
+    }
+    Ok(())
+}
+
+fn remove_wallet_metadata(conn: &mut Connection, wallet_name: &str) -> Result<()> {
+    let tx = conn.transaction()?;
+    for table in WALLET_NAME_TABLES {
+        tx.execute(
+            &format!("DELETE FROM {} WHERE wallet_name = ?1", table),
+            params![wallet_name],
+        )?;
+    }
+    tx.commit().context("Failed to remove wallet metadata")
+}
+
+fn existing_tables(conn: &Connection, tables: &[&'static str]) -> Result<Vec<&'static str>> {
+    let mut stmt = conn.prepare(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1)",
+    )?;
+    let mut existing = Vec::with_capacity(tables.len());
+    for &table in tables {
+        if stmt.query_row(params![table], |row| row.get(0))? {
+            existing.push(table);
+        }
+    }
+    Ok(existing)
+}
+
+pub(crate) fn parse_passphrase(passphrase: Option<String>) -> Result<Option<SafePassword>> {
+    passphrase
+        .map(|p| SafePassword::from_str(&p))
+        .transpose()
+        .map_err(|_| anyhow!("Invalid password"))
+}
+
+pub(crate) fn decode_seed(
+    seed_words: &[String],
+    password: Option<SafePassword>,
+) -> Result<CipherSeed> {
+    let seed_words: Vec<String> = seed_words.iter().map(|w| normalize_word(w)).collect();
+    let mnemonic = SeedWords::from_str(&seed_words.join(" ")).context("Invalid seed words")?;
+    CipherSeed::from_mnemonic(&mnemonic, password).context("Invalid cipher seed")
+}
+
+pub(crate) fn key_manager_from_seed(seed: CipherSeed) -> Result<KeyManager> {
     let wallet_type = WalletType::SeedWords(
         SeedWordsWallet::construct_new(seed)
             .map_err(|_| anyhow!("Failed to construct wallet from seed"))?,
     );
-    let key_manager = KeyManager::new(wallet_type).context("Failed to create key manager")?;
+    KeyManager::new(wallet_type).context("Failed to create key manager")
+}
+
+fn generate_details_from_seed(
+    seed: CipherSeed,
+    password: Option<SafePassword>,
+    network: Network,
+    language: SeedLanguage,
+) -> Result<WalletCreationDetails> {
+    let wallet_birthday = seed.birthday();
+    let mnemonic = seed
+        .to_mnemonic(language.into(), password)
+        .context("Failed to encode seed words")?;
+    let seed_words = seed_words_to_vec(&mnemonic)?;
+
+    let key_manager = key_manager_from_seed(seed)?;
 
     let view_key = key_manager.get_private_view_key();
     let spend_key = key_manager.get_spend_key();
@@ -81,20 +497,82 @@ fn generate_details_from_seed(seed: CipherSeed, network: Network) -> Result<Wall
         wallet_birthday,
         spend_public_key_hex: spend_key.pub_key.to_hex(),
         view_private_key_hex: view_key.to_hex(),
+        seed_words,
+        seed_language: language,
+        estimated_scan_start_height: 0,
     })
 }
 
-fn initialize_wallet(details: &WalletCreationDetails) -> Result<()> {
+/// Estimates where scanning a wallet with `birthday` begins, counting from the genesis block's
+/// time as reported by the network's base node. Falls back to 0 when no node answers.
+async fn estimate_scan_start_height(network: Network, birthday: u16) -> u64 {
+    match block_time(network, 0).await {
+        Ok(genesis_timestamp) => birthday_to_height(birthday, network, genesis_timestamp),
+        Err(_) => 0,
+    }
+}
+
+/// Timestamp of the block at `height`, read from the network's base nodes.
+async fn block_time(network: Network, height: u64) -> Result<u64> {
+    let candidates = {
+        let conn = get_db_connection()?;
+        node_candidates(&conn, network, None)?
+    };
+    let proxy = resolve_proxy(None)?;
+    block_timestamp(&candidates, height, proxy.as_ref()).await
+}
+
+enum WalletOrigin {
+    Created,
+    Restored,
+    ViewOnly,
+}
+
+fn initialize_wallet(
+    wallet_name: &str,
+    details: &WalletCreationDetails,
+    network: Network,
+    scanner_birthday: u16,
+    origin: WalletOrigin,
+) -> Result<()> {
+    // Serialized so a concurrent initialization's metadata row is never taken for a stale one.
+    let _guard = WALLET_INIT_LOCK
+        .lock()
+        .map_err(|_| anyhow!("Wallet initialization lock poisoned"))?;
+    let mut conn = get_db_connection()?;
+    ensure_wallet_name_available(&mut conn, wallet_name)?;
+
+    // The scanner account is created on its own connection, so the two writes can't share a
+    // transaction. Metadata goes first and is rolled back by hand if the account can't be created.
+    conn.execute(
+        "INSERT INTO cw_wallets
+            (wallet_name, network, birthday, spend_public_key_hex, is_backed_up,
+             scan_start_height, is_watch_only)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            wallet_name,
+            network.to_string(),
+            details.wallet_birthday,
+            details.spend_public_key_hex,
+            matches!(origin, WalletOrigin::Restored),
+            details.estimated_scan_start_height,
+            matches!(origin, WalletOrigin::ViewOnly),
+        ],
+    )
+    .context("Failed to store wallet metadata")?;
+
     let db = get_db_path()?;
-    init_with_view_key(
+    if let Err(e) = init_with_view_key(
         &details.view_private_key_hex,
         &details.spend_public_key_hex,
         "",
         &db,
-        0,
-        None,
-    )
-    .context("failed to initialize wallet")?;
+        scanner_birthday,
+        Some(wallet_name),
+    ) {
+        remove_wallet_metadata(&mut conn, wallet_name)?;
+        return Err(e).context("failed to initialize wallet");
+    }
 
     Ok(())
 }
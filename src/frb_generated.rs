@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1858936417;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -397924112;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__wallet__create_wallet_impl(
+fn wire__crate__api__network_profiles__activate_network_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__wallet__create_wallet_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_wallet",
+            debug_name: "activate_network_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,12 +67,16 @@ fn wire__crate__api__wallet__create_wallet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::wallet::create_wallet(api_network)?;
+                        let output_ok = crate::api::network_profiles::activate_network_profile(
+                            api_network,
+                            api_name,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -80,7 +84,7 @@ fn wire__crate__api__wallet__create_wallet_impl(
         },
     )
 }
-fn wire__crate__api__db__disconnect_database_impl(
+fn wire__crate__api__contacts__add_contact_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -88,7 +92,7 @@ fn wire__crate__api__db__disconnect_database_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "disconnect_database",
+            debug_name: "add_contact",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -102,11 +106,20 @@ fn wire__crate__api__db__disconnect_database_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_notes = <Option<String>>::sse_decode(&mut deserializer);
+            let api_is_favourite = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::db::disconnect_database()?;
+                        let output_ok = crate::api::contacts::add_contact(
+                            api_name,
+                            api_address,
+                            api_notes,
+                            api_is_favourite,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -114,7 +127,7 @@ fn wire__crate__api__db__disconnect_database_impl(
         },
     )
 }
-fn wire__crate__api__address__get_address_impl(
+fn wire__crate__api__node__add_node_endpoint_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -122,7 +135,7 @@ fn wire__crate__api__address__get_address_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_address",
+            debug_name: "add_node_endpoint",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -136,18 +149,14 @@ fn wire__crate__api__address__get_address_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
-            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
-            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::address::get_address(
-                            api_wallet_name,
-                            api_passphrase,
-                            api_network,
-                        )?;
+                        let output_ok =
+                            crate::api::node::add_node_endpoint(api_network, api_base_url)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -155,7 +164,55 @@ fn wire__crate__api__address__get_address_impl(
         },
     )
 }
-fn wire__crate__api__balance__get_balance_impl(
+fn wire__crate__api__offline_signing__broadcast_signed_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "broadcast_signed_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_base_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_confirmation_window = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_proxy =
+                <Option<crate::api::proxy::ProxySettingsDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::offline_signing::broadcast_signed_transaction(
+                            api_payload_json,
+                            api_passphrase,
+                            api_base_url,
+                            api_confirmation_window,
+                            api_proxy,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet__change_seed_passphrase_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -163,7 +220,7 @@ fn wire__crate__api__balance__get_balance_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_balance",
+            debug_name: "change_seed_passphrase",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -178,11 +235,19 @@ fn wire__crate__api__balance__get_balance_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_seed_words = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_old_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_new_passphrase = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::balance::get_balance(api_wallet_name)?;
+                        let output_ok = crate::api::wallet::change_seed_passphrase(
+                            api_wallet_name,
+                            api_seed_words,
+                            api_old_passphrase,
+                            api_new_passphrase,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -190,7 +255,7 @@ fn wire__crate__api__balance__get_balance_impl(
         },
     )
 }
-fn wire__crate__api__transactions__get_transactions_impl(
+fn wire__crate__api__wallet__confirm_seed_backup_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -198,7 +263,7 @@ fn wire__crate__api__transactions__get_transactions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_transactions",
+            debug_name: "confirm_seed_backup",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -213,16 +278,16 @@ fn wire__crate__api__transactions__get_transactions_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <i64>::sse_decode(&mut deserializer);
-            let api_offset = <i64>::sse_decode(&mut deserializer);
+            let api_seed_words = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::transactions::get_transactions(
+                        let output_ok = crate::api::wallet::confirm_seed_backup(
                             api_wallet_name,
-                            api_limit,
-                            api_offset,
+                            api_seed_words,
+                            api_passphrase,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -231,7 +296,7 @@ fn wire__crate__api__transactions__get_transactions_impl(
         },
     )
 }
-fn wire__crate__api__db__initialize_database_impl(
+fn wire__crate__api__integrated_addresses__create_integrated_address_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -239,7 +304,7 @@ fn wire__crate__api__db__initialize_database_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_database",
+            debug_name: "create_integrated_address",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -253,12 +318,30 @@ fn wire__crate__api__db__initialize_database_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            let api_payment_id = <String>::sse_decode(&mut deserializer);
+            let api_features =
+                <Option<crate::api::integrated_addresses::AddressFeaturesDto>>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_label = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::db::initialize_database(api_path)?;
+                        let output_ok =
+                            crate::api::integrated_addresses::create_integrated_address(
+                                api_wallet_name,
+                                api_account_id,
+                                api_passphrase,
+                                api_network,
+                                api_payment_id,
+                                api_features,
+                                api_label,
+                            )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -266,7 +349,53 @@ fn wire__crate__api__db__initialize_database_impl(
         },
     )
 }
-fn wire__crate__api__seeds__list_words_impl(
+fn wire__crate__api__wallet__create_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_language =
+                <Option<crate::api::seeds::SeedLanguage>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet::create_wallet(
+                            api_wallet_name,
+                            api_network,
+                            api_passphrase,
+                            api_language,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__offline_signing__decode_qr_chunks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -274,7 +403,7 @@ fn wire__crate__api__seeds__list_words_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_words",
+            debug_name: "decode_qr_chunks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -288,17 +417,20 @@ fn wire__crate__api__seeds__list_words_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_chunks = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::seeds::list_words())?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::offline_signing::decode_qr_chunks(api_chunks)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__wallet__restore_wallet_impl(
+fn wire__crate__api__contacts__delete_contact_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -306,7 +438,7 @@ fn wire__crate__api__wallet__restore_wallet_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "restore_wallet",
+            debug_name: "delete_contact",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -320,18 +452,12 @@ fn wire__crate__api__wallet__restore_wallet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_seed_words = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
-            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            let api_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::wallet::restore_wallet(
-                            api_seed_words,
-                            api_passphrase,
-                            api_network,
-                        )?;
+                        let output_ok = crate::api::contacts::delete_contact(api_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -339,15 +465,15 @@ fn wire__crate__api__wallet__restore_wallet_impl(
         },
     )
 }
-fn wire__crate__api__send_transaction__send_transaction_impl(
+fn wire__crate__api__network_profiles__delete_custom_network_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_transaction",
+            debug_name: "delete_custom_network_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -361,37 +487,29 @@ fn wire__crate__api__send_transaction__send_transaction_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::send_transaction::SendTransactionEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
-            let api_details = <crate::api::send_transaction::SendTransactionDetails>::sse_decode(
-                &mut deserializer,
-            );
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
+                    (move || {
                         let output_ok =
-                            crate::api::send_transaction::send_transaction(api_sink, api_details)
-                                .await?;
+                            crate::api::network_profiles::delete_custom_network_profile(api_name)?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__scanner__start_scan_impl(
+fn wire__crate__api__integrated_addresses__delete_integrated_address_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_scan",
+            debug_name: "delete_integrated_address",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -405,27 +523,25 @@ fn wire__crate__api__scanner__start_scan_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::scanner::ScanEventDto,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
-            let api_config =
-                <crate::api::scanner::ScanConfiguration>::sse_decode(&mut deserializer);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_payment_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
+                    (move || {
                         let output_ok =
-                            crate::api::scanner::start_scan(api_sink, api_config).await?;
+                            crate::api::integrated_addresses::delete_integrated_address(
+                                api_wallet_name,
+                                api_payment_id,
+                            )?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__scanner__stop_scan_impl(
+fn wire__crate__api__wallet__delete_wallet_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -433,7 +549,7 @@ fn wire__crate__api__scanner__stop_scan_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_scan",
+            debug_name: "delete_wallet",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -447,11 +563,12 @@ fn wire__crate__api__scanner__stop_scan_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::scanner::stop_scan()?;
+                        let output_ok = crate::api::wallet::delete_wallet(api_wallet_name)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -459,281 +576,4199 @@ fn wire__crate__api__scanner__stop_scan_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<crate::api::scanner::ScanEventDto, flutter_rust_bridge::for_generated::SseCodec>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<
-        crate::api::send_transaction::SendTransactionEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__seeds__detect_seed_language_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_seed_language",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_words = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::seeds::detect_seed_language(api_words))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::api::balance::AccountBalanceDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_total = <u64>::sse_decode(deserializer);
-        let mut var_unconfirmed = <u64>::sse_decode(deserializer);
-        let mut var_locked = <u64>::sse_decode(deserializer);
+fn wire__crate__api__db__disconnect_database_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "disconnect_database",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::db::disconnect_database()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__payment_uri__encode_payment_uri_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encode_payment_uri",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_request =
+                <crate::api::payment_uri::PaymentRequestDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::payment_uri::encode_payment_uri(api_request)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__offline_signing__export_unsigned_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_unsigned_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_details = <crate::api::send_transaction::SendTransactionDetails>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::offline_signing::export_unsigned_transaction(api_details)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__find_contact_by_name_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_contact_by_name",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::contacts::find_contact_by_name(api_name)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__integrated_addresses__find_integrated_address_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_integrated_address",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_payment_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::integrated_addresses::find_integrated_address(
+                            api_wallet_name,
+                            api_payment_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__address__get_address_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_address",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::address::get_address(
+                            api_wallet_name,
+                            api_account_id,
+                            api_passphrase,
+                            api_network,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__balance__get_balance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_balance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::balance::get_balance(api_wallet_name, api_account_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__balance__get_balance_breakdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_balance_breakdown",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::balance::get_balance_breakdown(
+                            api_wallet_name,
+                            api_account_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__balance__get_balance_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_balance_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_from_height = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_to_height = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_granularity =
+                <crate::api::balance::BalanceGranularity>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::balance::get_balance_history(
+                            api_wallet_name,
+                            api_account_id,
+                            api_from_height,
+                            api_to_height,
+                            api_granularity,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network_profiles__get_network_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_network_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::network_profiles::get_network_profile(api_network)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__node__get_node_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_node_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::node::get_node_status(api_network).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__portfolio__get_portfolio_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_portfolio",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::portfolio::get_portfolio()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__proxy__get_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::proxy::get_proxy()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__transactions__get_transactions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_transactions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_limit = <i64>::sse_decode(&mut deserializer);
+            let api_offset = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::transactions::get_transactions(
+                            api_wallet_name,
+                            api_account_id,
+                            api_limit,
+                            api_offset,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vault__has_vault_seed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "has_vault_seed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault::has_vault_seed(api_wallet_name)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet__import_view_only_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_view_only_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_view_key_hex = <String>::sse_decode(&mut deserializer);
+            let api_spend_pub_hex = <String>::sse_decode(&mut deserializer);
+            let api_birthday = <Option<u16>>::sse_decode(&mut deserializer);
+            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet::import_view_only_wallet(
+                            api_view_key_hex,
+                            api_spend_pub_hex,
+                            api_birthday,
+                            api_network,
+                            api_wallet_name,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__db__initialize_database_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_database",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::db::initialize_database(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__address__inspect_address_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "inspect_address",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            let api_expected_network = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::address::inspect_address(
+                        api_input,
+                        api_expected_network,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__offline_signing__inspect_unsigned_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "inspect_unsigned_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::offline_signing::inspect_unsigned_transaction(
+                            api_payload_json,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vault__is_wallet_unlocked_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_wallet_unlocked",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault::is_wallet_unlocked(api_wallet_name)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__list_accounts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_accounts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::accounts::list_accounts(api_wallet_name)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__list_contacts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_contacts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::contacts::list_contacts()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__integrated_addresses__list_integrated_addresses_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_integrated_addresses",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::integrated_addresses::list_integrated_addresses(
+                                api_wallet_name,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network_profiles__list_network_profiles_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_network_profiles",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::network_profiles::list_network_profiles()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__node__list_node_endpoints_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_node_endpoints",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::node::list_node_endpoints(api_network)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__outputs__list_outputs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_outputs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::outputs::OutputFilterDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::outputs::list_outputs(
+                            api_wallet_name,
+                            api_account_id,
+                            api_filter,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet__list_wallets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_wallets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::wallet::list_wallets()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__seeds__list_words_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_words",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_language =
+                <Option<crate::api::seeds::SeedLanguage>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::seeds::list_words(api_language))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__vault__lock_all_wallets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lock_all_wallets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault::lock_all_wallets()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vault__lock_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lock_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault::lock_wallet(api_wallet_name)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__payment_uri__parse_payment_uri_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_payment_uri",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_uri = <String>::sse_decode(&mut deserializer);
+            let api_expected_network = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::payment_uri::parse_payment_uri(
+                            api_uri,
+                            api_expected_network,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__node__remove_node_endpoint_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_node_endpoint",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::node::remove_node_endpoint(api_network, api_base_url)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vault__remove_seed_from_vault_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_seed_from_vault",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault::remove_seed_from_vault(api_wallet_name)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet__rename_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rename_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <String>::sse_decode(&mut deserializer);
+            let api_new_wallet_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::wallet::rename_wallet(
+                            api_wallet_name,
+                            api_new_wallet_name,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network_profiles__reset_network_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_network_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::network_profiles::reset_network_profile(api_network)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet__restore_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_seed_words = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_network = <Option<String>>::sse_decode(&mut deserializer);
+            let api_start_height = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet::restore_wallet(
+                            api_wallet_name,
+                            api_seed_words,
+                            api_passphrase,
+                            api_network,
+                            api_start_height,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__send_transaction__send_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::send_transaction::SendTransactionEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_details = <crate::api::send_transaction::SendTransactionDetails>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::send_transaction::send_transaction(api_sink, api_details)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network_profiles__set_custom_network_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_custom_network_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_batch_size = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_confirmation_window = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::network_profiles::set_custom_network_profile(
+                            api_name,
+                            api_network,
+                            api_base_url,
+                            api_batch_size,
+                            api_confirmation_window,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network_profiles__set_network_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_network_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_network = <String>::sse_decode(&mut deserializer);
+            let api_base_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_batch_size = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_confirmation_window = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::network_profiles::set_network_profile(
+                            api_network,
+                            api_base_url,
+                            api_batch_size,
+                            api_confirmation_window,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__proxy__set_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <Option<crate::api::proxy::ProxySettingsDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::proxy::set_proxy(api_settings)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__offline_signing__sign_offline_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sign_offline_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_seed_words = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_confirmed =
+                <crate::api::offline_signing::OfflineTransactionSummaryDto>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::offline_signing::sign_offline_transaction(
+                            api_payload_json,
+                            api_seed_words,
+                            api_passphrase,
+                            api_confirmed,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__start_scan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_scan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::scanner::ScanEventDto,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::scanner::ScanConfiguration>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::scanner::start_scan(api_sink, api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__stop_scan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_scan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::scanner::stop_scan()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vault__store_seed_in_vault_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "store_seed_in_vault",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_seed_words = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_pin = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault::store_seed_in_vault(
+                            api_wallet_name,
+                            api_seed_words,
+                            api_passphrase,
+                            api_pin,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vault__unlock_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_pin = <String>::sse_decode(&mut deserializer);
+            let api_auto_lock_seconds = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault::unlock_wallet(
+                            api_wallet_name,
+                            api_pin,
+                            api_auto_lock_seconds,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__update_contact_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_contact",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_notes = <Option<String>>::sse_decode(&mut deserializer);
+            let api_is_favourite = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::contacts::update_contact(
+                            api_id,
+                            api_name,
+                            api_address,
+                            api_notes,
+                            api_is_favourite,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__seeds__validate_seed_words_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_seed_words",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_words = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+            let api_language =
+                <Option<crate::api::seeds::SeedLanguage>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::seeds::validate_seed_words(
+                        api_words,
+                        api_passphrase,
+                        api_language,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__balance__watch_balance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_balance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::balance::AccountBalanceDto,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_wallet_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::balance::watch_balance(
+                            api_sink,
+                            api_wallet_name,
+                            api_account_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::balance::AccountBalanceDto,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::scanner::ScanEventDto, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::send_transaction::SendTransactionEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::balance::AccountBalanceDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_total = <u64>::sse_decode(deserializer);
+        let mut var_unconfirmed = <u64>::sse_decode(deserializer);
+        let mut var_locked = <u64>::sse_decode(deserializer);
         let mut var_available = <u64>::sse_decode(deserializer);
         return crate::api::balance::AccountBalanceDto {
             total: var_total,
-            unconfirmed: var_unconfirmed,
-            locked: var_locked,
-            available: var_available,
+            unconfirmed: var_unconfirmed,
+            locked: var_locked,
+            available: var_available,
+        };
+    }
+}
+
+impl SseDecode for crate::api::accounts::AccountDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_accountId = <i64>::sse_decode(deserializer);
+        let mut var_walletName = <String>::sse_decode(deserializer);
+        let mut var_balance = <crate::api::balance::AccountBalanceDto>::sse_decode(deserializer);
+        return crate::api::accounts::AccountDto {
+            account_id: var_accountId,
+            wallet_name: var_walletName,
+            balance: var_balance,
+        };
+    }
+}
+
+impl SseDecode for crate::api::integrated_addresses::AddressFeaturesDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_oneSided = <bool>::sse_decode(deserializer);
+        let mut var_interactive = <bool>::sse_decode(deserializer);
+        return crate::api::integrated_addresses::AddressFeaturesDto {
+            one_sided: var_oneSided,
+            interactive: var_interactive,
+        };
+    }
+}
+
+impl SseDecode for crate::api::address::AddressFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::address::AddressFormat::Base58,
+            1 => crate::api::address::AddressFormat::Emoji,
+            2 => crate::api::address::AddressFormat::Hex,
+            _ => unreachable!("Invalid variant for AddressFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::address::AddressInspectionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_isValid = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_format = <crate::api::address::AddressFormat>::sse_decode(deserializer);
+        let mut var_checksumValid = <bool>::sse_decode(deserializer);
+        let mut var_network = <Option<String>>::sse_decode(deserializer);
+        let mut var_networkMatches = <Option<bool>>::sse_decode(deserializer);
+        let mut var_isDualAddress = <bool>::sse_decode(deserializer);
+        let mut var_supportsOneSided = <bool>::sse_decode(deserializer);
+        let mut var_supportsInteractive = <bool>::sse_decode(deserializer);
+        let mut var_hasPaymentId = <bool>::sse_decode(deserializer);
+        let mut var_paymentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_paymentIdHex = <Option<String>>::sse_decode(deserializer);
+        let mut var_publicViewKeyHex = <Option<String>>::sse_decode(deserializer);
+        let mut var_publicSpendKeyHex = <Option<String>>::sse_decode(deserializer);
+        let mut var_base58 = <Option<String>>::sse_decode(deserializer);
+        let mut var_emoji = <Option<String>>::sse_decode(deserializer);
+        return crate::api::address::AddressInspectionDto {
+            is_valid: var_isValid,
+            error: var_error,
+            format: var_format,
+            checksum_valid: var_checksumValid,
+            network: var_network,
+            network_matches: var_networkMatches,
+            is_dual_address: var_isDualAddress,
+            supports_one_sided: var_supportsOneSided,
+            supports_interactive: var_supportsInteractive,
+            has_payment_id: var_hasPaymentId,
+            payment_id: var_paymentId,
+            payment_id_hex: var_paymentIdHex,
+            public_view_key_hex: var_publicViewKeyHex,
+            public_spend_key_hex: var_publicSpendKeyHex,
+            base58: var_base58,
+            emoji: var_emoji,
+        };
+    }
+}
+
+impl SseDecode for crate::api::balance::BalanceBreakdownDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_accountId = <i64>::sse_decode(deserializer);
+        let mut var_tipHeight = <u64>::sse_decode(deserializer);
+        let mut var_confirmationWindow = <u64>::sse_decode(deserializer);
+        let mut var_available = <u64>::sse_decode(deserializer);
+        let mut var_pendingIncoming =
+            <crate::api::balance::BalanceBucketDto>::sse_decode(deserializer);
+        let mut var_pendingOutgoing =
+            <crate::api::balance::BalanceBucketDto>::sse_decode(deserializer);
+        let mut var_locked = <crate::api::balance::BalanceBucketDto>::sse_decode(deserializer);
+        let mut var_immatureCoinbase =
+            <crate::api::balance::BalanceBucketDto>::sse_decode(deserializer);
+        let mut var_timeLocked = <crate::api::balance::BalanceBucketDto>::sse_decode(deserializer);
+        return crate::api::balance::BalanceBreakdownDto {
+            account_id: var_accountId,
+            tip_height: var_tipHeight,
+            confirmation_window: var_confirmationWindow,
+            available: var_available,
+            pending_incoming: var_pendingIncoming,
+            pending_outgoing: var_pendingOutgoing,
+            locked: var_locked,
+            immature_coinbase: var_immatureCoinbase,
+            time_locked: var_timeLocked,
+        };
+    }
+}
+
+impl SseDecode for crate::api::balance::BalanceBucketDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_total = <u64>::sse_decode(deserializer);
+        let mut var_entries = <Vec<crate::api::balance::BalanceEntryDto>>::sse_decode(deserializer);
+        return crate::api::balance::BalanceBucketDto {
+            total: var_total,
+            entries: var_entries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::balance::BalanceEntryDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_outputHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_transactionId = <Option<String>>::sse_decode(deserializer);
+        let mut var_spendableAtHeight = <Option<u64>>::sse_decode(deserializer);
+        let mut var_spendableAt = <Option<String>>::sse_decode(deserializer);
+        return crate::api::balance::BalanceEntryDto {
+            amount: var_amount,
+            output_hash: var_outputHash,
+            transaction_id: var_transactionId,
+            spendable_at_height: var_spendableAtHeight,
+            spendable_at: var_spendableAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::balance::BalanceGranularity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::balance::BalanceGranularity::Block,
+            1 => crate::api::balance::BalanceGranularity::Day,
+            _ => unreachable!("Invalid variant for BalanceGranularity: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::balance::BalancePointDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blockHeight = <u64>::sse_decode(deserializer);
+        let mut var_date = <Option<String>>::sse_decode(deserializer);
+        let mut var_balance = <u64>::sse_decode(deserializer);
+        let mut var_received = <u64>::sse_decode(deserializer);
+        let mut var_spent = <u64>::sse_decode(deserializer);
+        return crate::api::balance::BalancePointDto {
+            block_height: var_blockHeight,
+            date: var_date,
+            balance: var_balance,
+            received: var_received,
+            spent: var_spent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transactions::BlockchainInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blockHeight = <u64>::sse_decode(deserializer);
+        let mut var_timestamp = <String>::sse_decode(deserializer);
+        let mut var_confirmations = <u64>::sse_decode(deserializer);
+        return crate::api::transactions::BlockchainInfoDto {
+            block_height: var_blockHeight,
+            timestamp: var_timestamp,
+            confirmations: var_confirmations,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::contacts::ContactDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_addressEmoji = <String>::sse_decode(deserializer);
+        let mut var_notes = <Option<String>>::sse_decode(deserializer);
+        let mut var_isFavourite = <bool>::sse_decode(deserializer);
+        let mut var_createdAt = <String>::sse_decode(deserializer);
+        return crate::api::contacts::ContactDto {
+            id: var_id,
+            name: var_name,
+            address: var_address,
+            address_emoji: var_addressEmoji,
+            notes: var_notes,
+            is_favourite: var_isFavourite,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transactions::CounterpartyInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_addressEmoji = <Option<String>>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        return crate::api::transactions::CounterpartyInfoDto {
+            address: var_address,
+            address_emoji: var_addressEmoji,
+            label: var_label,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transactions::DisplayedTransactionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_direction = <String>::sse_decode(deserializer);
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_amountDisplay = <String>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        let mut var_counterparty =
+            <Option<crate::api::transactions::CounterpartyInfoDto>>::sse_decode(deserializer);
+        let mut var_blockchain =
+            <crate::api::transactions::BlockchainInfoDto>::sse_decode(deserializer);
+        let mut var_fee = <Option<crate::api::transactions::FeeInfoDto>>::sse_decode(deserializer);
+        let mut var_integratedAddress = <Option<
+            crate::api::integrated_addresses::IntegratedAddressDto,
+        >>::sse_decode(deserializer);
+        return crate::api::transactions::DisplayedTransactionDto {
+            id: var_id,
+            direction: var_direction,
+            source: var_source,
+            status: var_status,
+            amount: var_amount,
+            amount_display: var_amountDisplay,
+            message: var_message,
+            counterparty: var_counterparty,
+            blockchain: var_blockchain,
+            fee: var_fee,
+            integrated_address: var_integratedAddress,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transactions::FeeInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_amountDisplay = <String>::sse_decode(deserializer);
+        return crate::api::transactions::FeeInfoDto {
+            amount: var_amount,
+            amount_display: var_amountDisplay,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::integrated_addresses::IntegratedAddressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletName = <String>::sse_decode(deserializer);
+        let mut var_accountId = <i64>::sse_decode(deserializer);
+        let mut var_paymentId = <String>::sse_decode(deserializer);
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_addressEmoji = <String>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <String>::sse_decode(deserializer);
+        return crate::api::integrated_addresses::IntegratedAddressDto {
+            wallet_name: var_walletName,
+            account_id: var_accountId,
+            payment_id: var_paymentId,
+            address: var_address,
+            address_emoji: var_addressEmoji,
+            label: var_label,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::accounts::AccountDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::accounts::AccountDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::balance::BalanceEntryDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::balance::BalanceEntryDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::balance::BalancePointDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::balance::BalancePointDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::contacts::ContactDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::contacts::ContactDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::transactions::DisplayedTransactionDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::transactions::DisplayedTransactionDto>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::integrated_addresses::IntegratedAddressDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::integrated_addresses::IntegratedAddressDto>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::portfolio::NetworkPortfolioDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::portfolio::NetworkPortfolioDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::network_profiles::NetworkProfileDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::network_profiles::NetworkProfileDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::node::NodeStatusDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::node::NodeStatusDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::offline_signing::OfflineRecipientDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::offline_signing::OfflineRecipientDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::outputs::OutputDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::outputs::OutputDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::outputs::OutputStatusDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::outputs::OutputStatusDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::seeds::SeedWordValidationDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::seeds::SeedWordValidationDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::wallet::WalletInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::wallet::WalletInfoDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::portfolio::WalletPortfolioDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::portfolio::WalletPortfolioDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::portfolio::NetworkPortfolioDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_network = <String>::sse_decode(deserializer);
+        let mut var_total = <crate::api::balance::AccountBalanceDto>::sse_decode(deserializer);
+        let mut var_wallets =
+            <Vec<crate::api::portfolio::WalletPortfolioDto>>::sse_decode(deserializer);
+        return crate::api::portfolio::NetworkPortfolioDto {
+            network: var_network,
+            total: var_total,
+            wallets: var_wallets,
+        };
+    }
+}
+
+impl SseDecode for crate::api::network_profiles::NetworkProfileDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_network = <String>::sse_decode(deserializer);
+        let mut var_baseUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_batchSize = <u64>::sse_decode(deserializer);
+        let mut var_confirmationWindow = <u64>::sse_decode(deserializer);
+        let mut var_isCustomized = <bool>::sse_decode(deserializer);
+        let mut var_isCustom = <bool>::sse_decode(deserializer);
+        let mut var_isActive = <bool>::sse_decode(deserializer);
+        return crate::api::network_profiles::NetworkProfileDto {
+            name: var_name,
+            network: var_network,
+            base_url: var_baseUrl,
+            batch_size: var_batchSize,
+            confirmation_window: var_confirmationWindow,
+            is_customized: var_isCustomized,
+            is_custom: var_isCustom,
+            is_active: var_isActive,
+        };
+    }
+}
+
+impl SseDecode for crate::api::node::NodeStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_baseUrl = <String>::sse_decode(deserializer);
+        let mut var_isReachable = <bool>::sse_decode(deserializer);
+        let mut var_isSynced = <bool>::sse_decode(deserializer);
+        let mut var_tipHeight = <Option<u64>>::sse_decode(deserializer);
+        let mut var_latencyMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_version = <Option<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::node::NodeStatusDto {
+            base_url: var_baseUrl,
+            is_reachable: var_isReachable,
+            is_synced: var_isSynced,
+            tip_height: var_tipHeight,
+            latency_ms: var_latencyMs,
+            version: var_version,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::offline_signing::OfflineRecipientDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_paymentId = <String>::sse_decode(deserializer);
+        return crate::api::offline_signing::OfflineRecipientDto {
+            address: var_address,
+            amount: var_amount,
+            payment_id: var_paymentId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::offline_signing::OfflineTransactionPayloadDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_json = <String>::sse_decode(deserializer);
+        let mut var_qrChunks = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::offline_signing::OfflineTransactionPayloadDto {
+            json: var_json,
+            qr_chunks: var_qrChunks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::offline_signing::OfflineTransactionSummaryDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_network = <String>::sse_decode(deserializer);
+        let mut var_walletName = <String>::sse_decode(deserializer);
+        let mut var_recipients =
+            <Vec<crate::api::offline_signing::OfflineRecipientDto>>::sse_decode(deserializer);
+        let mut var_fee = <u64>::sse_decode(deserializer);
+        let mut var_total = <u64>::sse_decode(deserializer);
+        return crate::api::offline_signing::OfflineTransactionSummaryDto {
+            network: var_network,
+            wallet_name: var_walletName,
+            recipients: var_recipients,
+            fee: var_fee,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::integrated_addresses::AddressFeaturesDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::integrated_addresses::AddressFeaturesDto>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::contacts::ContactDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::contacts::ContactDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::transactions::CounterpartyInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::transactions::CounterpartyInfoDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::transactions::FeeInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::transactions::FeeInfoDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::integrated_addresses::IntegratedAddressDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::integrated_addresses::IntegratedAddressDto>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::outputs::OutputFilterDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::outputs::OutputFilterDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::proxy::ProxySettingsDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::proxy::ProxySettingsDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::seeds::SeedLanguage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::seeds::SeedLanguage>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::outputs::OutputStatusDto>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::outputs::OutputStatusDto>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::outputs::OutputDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputHash = <String>::sse_decode(deserializer);
+        let mut var_commitment = <String>::sse_decode(deserializer);
+        let mut var_value = <u64>::sse_decode(deserializer);
+        let mut var_status = <crate::api::outputs::OutputStatusDto>::sse_decode(deserializer);
+        let mut var_outputType = <String>::sse_decode(deserializer);
+        let mut var_minedHeight = <u64>::sse_decode(deserializer);
+        let mut var_maturityHeight = <u64>::sse_decode(deserializer);
+        let mut var_isMature = <bool>::sse_decode(deserializer);
+        let mut var_lockExpiresAt = <Option<String>>::sse_decode(deserializer);
+        let mut var_paymentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdByTransactionId = <Option<String>>::sse_decode(deserializer);
+        let mut var_lockedByRequestId = <Option<String>>::sse_decode(deserializer);
+        let mut var_spentByTransactionId = <Option<String>>::sse_decode(deserializer);
+        let mut var_spentHeight = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::outputs::OutputDto {
+            output_hash: var_outputHash,
+            commitment: var_commitment,
+            value: var_value,
+            status: var_status,
+            output_type: var_outputType,
+            mined_height: var_minedHeight,
+            maturity_height: var_maturityHeight,
+            is_mature: var_isMature,
+            lock_expires_at: var_lockExpiresAt,
+            payment_id: var_paymentId,
+            created_by_transaction_id: var_createdByTransactionId,
+            locked_by_request_id: var_lockedByRequestId,
+            spent_by_transaction_id: var_spentByTransactionId,
+            spent_height: var_spentHeight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::outputs::OutputFilterDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_statuses =
+            <Option<Vec<crate::api::outputs::OutputStatusDto>>>::sse_decode(deserializer);
+        let mut var_minValue = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxValue = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::outputs::OutputFilterDto {
+            statuses: var_statuses,
+            min_value: var_minValue,
+            max_value: var_maxValue,
+        };
+    }
+}
+
+impl SseDecode for crate::api::outputs::OutputStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::outputs::OutputStatusDto::Unspent,
+            1 => crate::api::outputs::OutputStatusDto::Locked,
+            2 => crate::api::outputs::OutputStatusDto::Spent,
+            3 => crate::api::outputs::OutputStatusDto::Pending,
+            _ => unreachable!("Invalid variant for OutputStatusDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::payment_uri::PaymentRequestDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_recipientAddress = <String>::sse_decode(deserializer);
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_paymentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_network = <Option<String>>::sse_decode(deserializer);
+        return crate::api::payment_uri::PaymentRequestDto {
+            recipient_address: var_recipientAddress,
+            amount: var_amount,
+            payment_id: var_paymentId,
+            label: var_label,
+            network: var_network,
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy::ProxyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::proxy::ProxyKind::Socks5,
+            1 => crate::api::proxy::ProxyKind::Http,
+            _ => unreachable!("Invalid variant for ProxyKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy::ProxySettingsDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::proxy::ProxyKind>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        return crate::api::proxy::ProxySettingsDto {
+            kind: var_kind,
+            host: var_host,
+            port: var_port,
+            username: var_username,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanConfiguration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_baseUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_batchSize = <Option<u64>>::sse_decode(deserializer);
+        let mut var_network = <Option<String>>::sse_decode(deserializer);
+        let mut var_continuous = <bool>::sse_decode(deserializer);
+        let mut var_pollIntervalSeconds = <u64>::sse_decode(deserializer);
+        let mut var_accountId = <Option<i64>>::sse_decode(deserializer);
+        let mut var_proxy = <Option<crate::api::proxy::ProxySettingsDto>>::sse_decode(deserializer);
+        return crate::api::scanner::ScanConfiguration {
+            password: var_password,
+            base_url: var_baseUrl,
+            batch_size: var_batchSize,
+            network: var_network,
+            continuous: var_continuous,
+            poll_interval_seconds: var_pollIntervalSeconds,
+            account_id: var_accountId,
+            proxy: var_proxy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanEventDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::scanner::ScanStatusDto>::sse_decode(deserializer);
+                return crate::api::scanner::ScanEventDto::Status(var_field0);
+            }
+            1 => {
+                let mut var_field0 =
+                    <crate::api::scanner::TransactionsReadyDto>::sse_decode(deserializer);
+                return crate::api::scanner::ScanEventDto::TransactionsReady(var_field0);
+            }
+            2 => {
+                let mut var_field0 =
+                    <crate::api::scanner::TransactionsUpdatedDto>::sse_decode(deserializer);
+                return crate::api::scanner::ScanEventDto::TransactionsUpdated(var_field0);
+            }
+            3 => {
+                let mut var_fromUrl = <String>::sse_decode(deserializer);
+                let mut var_toUrl = <String>::sse_decode(deserializer);
+                return crate::api::scanner::ScanEventDto::NodeChanged {
+                    from_url: var_fromUrl,
+                    to_url: var_toUrl,
+                };
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::scanner::ScanEventDto::Error(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_accountId = <i64>::sse_decode(deserializer);
+                let mut var_fromHeight = <u64>::sse_decode(deserializer);
+                return crate::api::scanner::ScanStatusDto::Started {
+                    account_id: var_accountId,
+                    from_height: var_fromHeight,
+                };
+            }
+            1 => {
+                let mut var_accountId = <i64>::sse_decode(deserializer);
+                let mut var_currentHeight = <u64>::sse_decode(deserializer);
+                let mut var_blocksScanned = <u64>::sse_decode(deserializer);
+                return crate::api::scanner::ScanStatusDto::Progress {
+                    account_id: var_accountId,
+                    current_height: var_currentHeight,
+                    blocks_scanned: var_blocksScanned,
+                };
+            }
+            2 => {
+                let mut var_accountId = <i64>::sse_decode(deserializer);
+                let mut var_finalHeight = <u64>::sse_decode(deserializer);
+                let mut var_totalBlocksScanned = <u64>::sse_decode(deserializer);
+                return crate::api::scanner::ScanStatusDto::Completed {
+                    account_id: var_accountId,
+                    final_height: var_finalHeight,
+                    total_blocks_scanned: var_totalBlocksScanned,
+                };
+            }
+            3 => {
+                let mut var_accountId = <i64>::sse_decode(deserializer);
+                let mut var_lastScannedHeight = <u64>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::scanner::ScanStatusDto::Paused {
+                    account_id: var_accountId,
+                    last_scanned_height: var_lastScannedHeight,
+                    reason: var_reason,
+                };
+            }
+            4 => {
+                let mut var_accountId = <i64>::sse_decode(deserializer);
+                let mut var_resumeInSeconds = <u64>::sse_decode(deserializer);
+                return crate::api::scanner::ScanStatusDto::Waiting {
+                    account_id: var_accountId,
+                    resume_in_seconds: var_resumeInSeconds,
+                };
+            }
+            5 => {
+                let mut var_accountId = <i64>::sse_decode(deserializer);
+                let mut var_lastScannedHeight = <u64>::sse_decode(deserializer);
+                return crate::api::scanner::ScanStatusDto::MoreBlocksAvailable {
+                    account_id: var_accountId,
+                    last_scanned_height: var_lastScannedHeight,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::seeds::SeedLanguage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::seeds::SeedLanguage::English,
+            1 => crate::api::seeds::SeedLanguage::ChineseSimplified,
+            2 => crate::api::seeds::SeedLanguage::French,
+            3 => crate::api::seeds::SeedLanguage::Italian,
+            4 => crate::api::seeds::SeedLanguage::Japanese,
+            5 => crate::api::seeds::SeedLanguage::Korean,
+            6 => crate::api::seeds::SeedLanguage::Spanish,
+            _ => unreachable!("Invalid variant for SeedLanguage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::seeds::SeedValidationDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_language = <crate::api::seeds::SeedLanguage>::sse_decode(deserializer);
+        let mut var_words =
+            <Vec<crate::api::seeds::SeedWordValidationDto>>::sse_decode(deserializer);
+        let mut var_unknownWordIndices = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_hasExpectedLength = <bool>::sse_decode(deserializer);
+        let mut var_checksumValid = <bool>::sse_decode(deserializer);
+        let mut var_decryptionSucceeded = <bool>::sse_decode(deserializer);
+        let mut var_birthday = <Option<u16>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::seeds::SeedValidationDto {
+            language: var_language,
+            words: var_words,
+            unknown_word_indices: var_unknownWordIndices,
+            has_expected_length: var_hasExpectedLength,
+            checksum_valid: var_checksumValid,
+            decryption_succeeded: var_decryptionSucceeded,
+            birthday: var_birthday,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::seeds::SeedWordValidationDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_word = <String>::sse_decode(deserializer);
+        let mut var_isValid = <bool>::sse_decode(deserializer);
+        let mut var_suggestions = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::seeds::SeedWordValidationDto {
+            index: var_index,
+            word: var_word,
+            is_valid: var_isValid,
+            suggestions: var_suggestions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::send_transaction::SendTransactionDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_seedWords = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_passphrase = <Option<String>>::sse_decode(deserializer);
+        let mut var_network = <Option<String>>::sse_decode(deserializer);
+        let mut var_baseUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_walletName = <Option<String>>::sse_decode(deserializer);
+        let mut var_accountId = <Option<i64>>::sse_decode(deserializer);
+        let mut var_recipientAddress = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_paymentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_confirmationWindow = <Option<u64>>::sse_decode(deserializer);
+        let mut var_proxy = <Option<crate::api::proxy::ProxySettingsDto>>::sse_decode(deserializer);
+        return crate::api::send_transaction::SendTransactionDetails {
+            seed_words: var_seedWords,
+            passphrase: var_passphrase,
+            network: var_network,
+            base_url: var_baseUrl,
+            wallet_name: var_walletName,
+            account_id: var_accountId,
+            recipient_address: var_recipientAddress,
+            amount: var_amount,
+            payment_id: var_paymentId,
+            confirmation_window: var_confirmationWindow,
+            proxy: var_proxy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::send_transaction::SendTransactionEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage =
+            <crate::api::send_transaction::TransactionStage>::sse_decode(deserializer);
+        let mut var_details = <String>::sse_decode(deserializer);
+        return crate::api::send_transaction::SendTransactionEvent {
+            stage: var_stage,
+            details: var_details,
+        };
+    }
+}
+
+impl SseDecode for crate::api::send_transaction::TransactionStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::send_transaction::TransactionStage::Initializing,
+            1 => crate::api::send_transaction::TransactionStage::ValidatingInput,
+            2 => crate::api::send_transaction::TransactionStage::ConnectingToNetwork,
+            3 => crate::api::send_transaction::TransactionStage::FetchingBalance,
+            4 => crate::api::send_transaction::TransactionStage::ConstructingTransaction,
+            5 => crate::api::send_transaction::TransactionStage::SigningKeyGeneration,
+            6 => crate::api::send_transaction::TransactionStage::SigningTransaction,
+            7 => crate::api::send_transaction::TransactionStage::Broadcasting,
+            8 => crate::api::send_transaction::TransactionStage::Completed,
+            _ => unreachable!("Invalid variant for TransactionStage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::TransactionsReadyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_accountId = <i64>::sse_decode(deserializer);
+        let mut var_transactions =
+            <Vec<crate::api::transactions::DisplayedTransactionDto>>::sse_decode(deserializer);
+        let mut var_blockHeight = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isInitialSync = <bool>::sse_decode(deserializer);
+        return crate::api::scanner::TransactionsReadyDto {
+            account_id: var_accountId,
+            transactions: var_transactions,
+            block_height: var_blockHeight,
+            is_initial_sync: var_isInitialSync,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::TransactionsUpdatedDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_accountId = <i64>::sse_decode(deserializer);
+        let mut var_updatedTransactions =
+            <Vec<crate::api::transactions::DisplayedTransactionDto>>::sse_decode(deserializer);
+        return crate::api::scanner::TransactionsUpdatedDto {
+            account_id: var_accountId,
+            updated_transactions: var_updatedTransactions,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::address::WalletAddressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_base58 = <String>::sse_decode(deserializer);
+        let mut var_emoji = <String>::sse_decode(deserializer);
+        return crate::api::address::WalletAddressDto {
+            base58: var_base58,
+            emoji: var_emoji,
+        };
+    }
+}
+
+impl SseDecode for crate::api::wallet::WalletCreationDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tariAddress = <String>::sse_decode(deserializer);
+        let mut var_walletBirthday = <u16>::sse_decode(deserializer);
+        let mut var_spendPublicKeyHex = <String>::sse_decode(deserializer);
+        let mut var_viewPrivateKeyHex = <String>::sse_decode(deserializer);
+        let mut var_seedWords = <Vec<String>>::sse_decode(deserializer);
+        let mut var_seedLanguage = <crate::api::seeds::SeedLanguage>::sse_decode(deserializer);
+        let mut var_estimatedScanStartHeight = <u64>::sse_decode(deserializer);
+        return crate::api::wallet::WalletCreationDetails {
+            tari_address: var_tariAddress,
+            wallet_birthday: var_walletBirthday,
+            spend_public_key_hex: var_spendPublicKeyHex,
+            view_private_key_hex: var_viewPrivateKeyHex,
+            seed_words: var_seedWords,
+            seed_language: var_seedLanguage,
+            estimated_scan_start_height: var_estimatedScanStartHeight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::wallet::WalletInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletName = <String>::sse_decode(deserializer);
+        let mut var_network = <String>::sse_decode(deserializer);
+        let mut var_walletBirthday = <u16>::sse_decode(deserializer);
+        let mut var_estimatedScanStartHeight = <u64>::sse_decode(deserializer);
+        let mut var_isBackedUp = <bool>::sse_decode(deserializer);
+        let mut var_isWatchOnly = <bool>::sse_decode(deserializer);
+        return crate::api::wallet::WalletInfoDto {
+            wallet_name: var_walletName,
+            network: var_network,
+            wallet_birthday: var_walletBirthday,
+            estimated_scan_start_height: var_estimatedScanStartHeight,
+            is_backed_up: var_isBackedUp,
+            is_watch_only: var_isWatchOnly,
+        };
+    }
+}
+
+impl SseDecode for crate::api::portfolio::WalletPortfolioDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletName = <String>::sse_decode(deserializer);
+        let mut var_total = <crate::api::balance::AccountBalanceDto>::sse_decode(deserializer);
+        let mut var_accounts = <Vec<crate::api::accounts::AccountDto>>::sse_decode(deserializer);
+        return crate::api::portfolio::WalletPortfolioDto {
+            wallet_name: var_walletName,
+            total: var_total,
+            accounts: var_accounts,
         };
     }
 }
-
-impl SseDecode for crate::api::transactions::BlockchainInfoDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_blockHeight = <u64>::sse_decode(deserializer);
-        let mut var_timestamp = <String>::sse_decode(deserializer);
-        let mut var_confirmations = <u64>::sse_decode(deserializer);
-        return crate::api::transactions::BlockchainInfoDto {
-            block_height: var_blockHeight,
-            timestamp: var_timestamp,
-            confirmations: var_confirmations,
-        };
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__network_profiles__activate_network_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__contacts__add_contact_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__node__add_node_endpoint_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__offline_signing__broadcast_signed_transaction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => {
+            wire__crate__api__wallet__change_seed_passphrase_impl(port, ptr, rust_vec_len, data_len)
+        }
+        6 => wire__crate__api__wallet__confirm_seed_backup_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__integrated_addresses__create_integrated_address_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__wallet__create_wallet_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__offline_signing__decode_qr_chunks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__contacts__delete_contact_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__network_profiles__delete_custom_network_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__integrated_addresses__delete_integrated_address_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__wallet__delete_wallet_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__seeds__detect_seed_language_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__db__disconnect_database_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__payment_uri__encode_payment_uri_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__offline_signing__export_unsigned_transaction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => {
+            wire__crate__api__contacts__find_contact_by_name_impl(port, ptr, rust_vec_len, data_len)
+        }
+        19 => wire__crate__api__integrated_addresses__find_integrated_address_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__address__get_address_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__balance__get_balance_impl(port, ptr, rust_vec_len, data_len),
+        22 => {
+            wire__crate__api__balance__get_balance_breakdown_impl(port, ptr, rust_vec_len, data_len)
+        }
+        23 => {
+            wire__crate__api__balance__get_balance_history_impl(port, ptr, rust_vec_len, data_len)
+        }
+        24 => wire__crate__api__network_profiles__get_network_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__node__get_node_status_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__portfolio__get_portfolio_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__proxy__get_proxy_impl(port, ptr, rust_vec_len, data_len),
+        28 => {
+            wire__crate__api__transactions__get_transactions_impl(port, ptr, rust_vec_len, data_len)
+        }
+        29 => wire__crate__api__vault__has_vault_seed_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__wallet__import_view_only_wallet_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__db__initialize_database_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__address__inspect_address_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__offline_signing__inspect_unsigned_transaction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__vault__is_wallet_unlocked_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__accounts__list_accounts_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__contacts__list_contacts_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__integrated_addresses__list_integrated_addresses_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__network_profiles__list_network_profiles_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__node__list_node_endpoints_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__outputs__list_outputs_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__wallet__list_wallets_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__seeds__list_words_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__vault__lock_all_wallets_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__vault__lock_wallet_impl(port, ptr, rust_vec_len, data_len),
+        45 => {
+            wire__crate__api__payment_uri__parse_payment_uri_impl(port, ptr, rust_vec_len, data_len)
+        }
+        46 => wire__crate__api__node__remove_node_endpoint_impl(port, ptr, rust_vec_len, data_len),
+        47 => {
+            wire__crate__api__vault__remove_seed_from_vault_impl(port, ptr, rust_vec_len, data_len)
+        }
+        48 => wire__crate__api__wallet__rename_wallet_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__network_profiles__reset_network_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__wallet__restore_wallet_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__send_transaction__send_transaction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__network_profiles__set_custom_network_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__network_profiles__set_network_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__proxy__set_proxy_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__offline_signing__sign_offline_transaction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__scanner__start_scan_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__scanner__stop_scan_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__vault__store_seed_in_vault_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__vault__unlock_wallet_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__contacts__update_contact_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__seeds__validate_seed_words_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__balance__watch_balance_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::balance::AccountBalanceDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.unconfirmed.into_into_dart().into_dart(),
+            self.locked.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::balance::AccountBalanceDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::balance::AccountBalanceDto>
+    for crate::api::balance::AccountBalanceDto
+{
+    fn into_into_dart(self) -> crate::api::balance::AccountBalanceDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::accounts::AccountDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.account_id.into_into_dart().into_dart(),
+            self.wallet_name.into_into_dart().into_dart(),
+            self.balance.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::accounts::AccountDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::AccountDto>
+    for crate::api::accounts::AccountDto
+{
+    fn into_into_dart(self) -> crate::api::accounts::AccountDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::integrated_addresses::AddressFeaturesDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.one_sided.into_into_dart().into_dart(),
+            self.interactive.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::integrated_addresses::AddressFeaturesDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::integrated_addresses::AddressFeaturesDto>
+    for crate::api::integrated_addresses::AddressFeaturesDto
+{
+    fn into_into_dart(self) -> crate::api::integrated_addresses::AddressFeaturesDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::address::AddressFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Base58 => 0.into_dart(),
+            Self::Emoji => 1.into_dart(),
+            Self::Hex => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::address::AddressFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::address::AddressFormat>
+    for crate::api::address::AddressFormat
+{
+    fn into_into_dart(self) -> crate::api::address::AddressFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::address::AddressInspectionDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.is_valid.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.checksum_valid.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.network_matches.into_into_dart().into_dart(),
+            self.is_dual_address.into_into_dart().into_dart(),
+            self.supports_one_sided.into_into_dart().into_dart(),
+            self.supports_interactive.into_into_dart().into_dart(),
+            self.has_payment_id.into_into_dart().into_dart(),
+            self.payment_id.into_into_dart().into_dart(),
+            self.payment_id_hex.into_into_dart().into_dart(),
+            self.public_view_key_hex.into_into_dart().into_dart(),
+            self.public_spend_key_hex.into_into_dart().into_dart(),
+            self.base58.into_into_dart().into_dart(),
+            self.emoji.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::address::AddressInspectionDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::address::AddressInspectionDto>
+    for crate::api::address::AddressInspectionDto
+{
+    fn into_into_dart(self) -> crate::api::address::AddressInspectionDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::balance::BalanceBreakdownDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.account_id.into_into_dart().into_dart(),
+            self.tip_height.into_into_dart().into_dart(),
+            self.confirmation_window.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+            self.pending_incoming.into_into_dart().into_dart(),
+            self.pending_outgoing.into_into_dart().into_dart(),
+            self.locked.into_into_dart().into_dart(),
+            self.immature_coinbase.into_into_dart().into_dart(),
+            self.time_locked.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::balance::BalanceBreakdownDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::balance::BalanceBreakdownDto>
+    for crate::api::balance::BalanceBreakdownDto
+{
+    fn into_into_dart(self) -> crate::api::balance::BalanceBreakdownDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::balance::BalanceBucketDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::balance::BalanceBucketDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::balance::BalanceBucketDto>
+    for crate::api::balance::BalanceBucketDto
+{
+    fn into_into_dart(self) -> crate::api::balance::BalanceBucketDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::balance::BalanceEntryDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.amount.into_into_dart().into_dart(),
+            self.output_hash.into_into_dart().into_dart(),
+            self.transaction_id.into_into_dart().into_dart(),
+            self.spendable_at_height.into_into_dart().into_dart(),
+            self.spendable_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::balance::BalanceEntryDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::balance::BalanceEntryDto>
+    for crate::api::balance::BalanceEntryDto
+{
+    fn into_into_dart(self) -> crate::api::balance::BalanceEntryDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::balance::BalanceGranularity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Block => 0.into_dart(),
+            Self::Day => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::balance::BalanceGranularity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::balance::BalanceGranularity>
+    for crate::api::balance::BalanceGranularity
+{
+    fn into_into_dart(self) -> crate::api::balance::BalanceGranularity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::balance::BalancePointDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.block_height.into_into_dart().into_dart(),
+            self.date.into_into_dart().into_dart(),
+            self.balance.into_into_dart().into_dart(),
+            self.received.into_into_dart().into_dart(),
+            self.spent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::balance::BalancePointDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::balance::BalancePointDto>
+    for crate::api::balance::BalancePointDto
+{
+    fn into_into_dart(self) -> crate::api::balance::BalancePointDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transactions::BlockchainInfoDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.block_height.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.confirmations.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transactions::BlockchainInfoDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::BlockchainInfoDto>
+    for crate::api::transactions::BlockchainInfoDto
+{
+    fn into_into_dart(self) -> crate::api::transactions::BlockchainInfoDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::contacts::ContactDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+            self.address_emoji.into_into_dart().into_dart(),
+            self.notes.into_into_dart().into_dart(),
+            self.is_favourite.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::contacts::ContactDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::contacts::ContactDto>
+    for crate::api::contacts::ContactDto
+{
+    fn into_into_dart(self) -> crate::api::contacts::ContactDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transactions::CounterpartyInfoDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.address_emoji.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transactions::CounterpartyInfoDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::CounterpartyInfoDto>
+    for crate::api::transactions::CounterpartyInfoDto
+{
+    fn into_into_dart(self) -> crate::api::transactions::CounterpartyInfoDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transactions::DisplayedTransactionDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.amount_display.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.counterparty.into_into_dart().into_dart(),
+            self.blockchain.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.integrated_address.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transactions::DisplayedTransactionDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::DisplayedTransactionDto>
+    for crate::api::transactions::DisplayedTransactionDto
+{
+    fn into_into_dart(self) -> crate::api::transactions::DisplayedTransactionDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transactions::FeeInfoDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.amount.into_into_dart().into_dart(),
+            self.amount_display.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transactions::FeeInfoDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::FeeInfoDto>
+    for crate::api::transactions::FeeInfoDto
+{
+    fn into_into_dart(self) -> crate::api::transactions::FeeInfoDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::integrated_addresses::IntegratedAddressDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_name.into_into_dart().into_dart(),
+            self.account_id.into_into_dart().into_dart(),
+            self.payment_id.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+            self.address_emoji.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::integrated_addresses::IntegratedAddressDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::integrated_addresses::IntegratedAddressDto>
+    for crate::api::integrated_addresses::IntegratedAddressDto
+{
+    fn into_into_dart(self) -> crate::api::integrated_addresses::IntegratedAddressDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::portfolio::NetworkPortfolioDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.network.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.wallets.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::portfolio::NetworkPortfolioDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::portfolio::NetworkPortfolioDto>
+    for crate::api::portfolio::NetworkPortfolioDto
+{
+    fn into_into_dart(self) -> crate::api::portfolio::NetworkPortfolioDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network_profiles::NetworkProfileDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.base_url.into_into_dart().into_dart(),
+            self.batch_size.into_into_dart().into_dart(),
+            self.confirmation_window.into_into_dart().into_dart(),
+            self.is_customized.into_into_dart().into_dart(),
+            self.is_custom.into_into_dart().into_dart(),
+            self.is_active.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::network_profiles::NetworkProfileDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network_profiles::NetworkProfileDto>
+    for crate::api::network_profiles::NetworkProfileDto
+{
+    fn into_into_dart(self) -> crate::api::network_profiles::NetworkProfileDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::node::NodeStatusDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.base_url.into_into_dart().into_dart(),
+            self.is_reachable.into_into_dart().into_dart(),
+            self.is_synced.into_into_dart().into_dart(),
+            self.tip_height.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::node::NodeStatusDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::node::NodeStatusDto>
+    for crate::api::node::NodeStatusDto
+{
+    fn into_into_dart(self) -> crate::api::node::NodeStatusDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::offline_signing::OfflineRecipientDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.payment_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::offline_signing::OfflineRecipientDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::offline_signing::OfflineRecipientDto>
+    for crate::api::offline_signing::OfflineRecipientDto
+{
+    fn into_into_dart(self) -> crate::api::offline_signing::OfflineRecipientDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::offline_signing::OfflineTransactionPayloadDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.json.into_into_dart().into_dart(),
+            self.qr_chunks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::offline_signing::OfflineTransactionPayloadDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::offline_signing::OfflineTransactionPayloadDto>
+    for crate::api::offline_signing::OfflineTransactionPayloadDto
+{
+    fn into_into_dart(self) -> crate::api::offline_signing::OfflineTransactionPayloadDto {
+        self
     }
 }
-
-impl SseDecode for crate::api::transactions::CounterpartyInfoDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_address = <String>::sse_decode(deserializer);
-        let mut var_addressEmoji = <Option<String>>::sse_decode(deserializer);
-        let mut var_label = <Option<String>>::sse_decode(deserializer);
-        return crate::api::transactions::CounterpartyInfoDto {
-            address: var_address,
-            address_emoji: var_addressEmoji,
-            label: var_label,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::offline_signing::OfflineTransactionSummaryDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.network.into_into_dart().into_dart(),
+            self.wallet_name.into_into_dart().into_dart(),
+            self.recipients.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::transactions::DisplayedTransactionDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_direction = <String>::sse_decode(deserializer);
-        let mut var_source = <String>::sse_decode(deserializer);
-        let mut var_status = <String>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_amountDisplay = <String>::sse_decode(deserializer);
-        let mut var_message = <Option<String>>::sse_decode(deserializer);
-        let mut var_counterparty =
-            <Option<crate::api::transactions::CounterpartyInfoDto>>::sse_decode(deserializer);
-        let mut var_blockchain =
-            <crate::api::transactions::BlockchainInfoDto>::sse_decode(deserializer);
-        let mut var_fee = <Option<crate::api::transactions::FeeInfoDto>>::sse_decode(deserializer);
-        return crate::api::transactions::DisplayedTransactionDto {
-            id: var_id,
-            direction: var_direction,
-            source: var_source,
-            status: var_status,
-            amount: var_amount,
-            amount_display: var_amountDisplay,
-            message: var_message,
-            counterparty: var_counterparty,
-            blockchain: var_blockchain,
-            fee: var_fee,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::offline_signing::OfflineTransactionSummaryDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::offline_signing::OfflineTransactionSummaryDto>
+    for crate::api::offline_signing::OfflineTransactionSummaryDto
+{
+    fn into_into_dart(self) -> crate::api::offline_signing::OfflineTransactionSummaryDto {
+        self
     }
 }
-
-impl SseDecode for crate::api::transactions::FeeInfoDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_amountDisplay = <String>::sse_decode(deserializer);
-        return crate::api::transactions::FeeInfoDto {
-            amount: var_amount,
-            amount_display: var_amountDisplay,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::outputs::OutputDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.output_hash.into_into_dart().into_dart(),
+            self.commitment.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.output_type.into_into_dart().into_dart(),
+            self.mined_height.into_into_dart().into_dart(),
+            self.maturity_height.into_into_dart().into_dart(),
+            self.is_mature.into_into_dart().into_dart(),
+            self.lock_expires_at.into_into_dart().into_dart(),
+            self.payment_id.into_into_dart().into_dart(),
+            self.created_by_transaction_id.into_into_dart().into_dart(),
+            self.locked_by_request_id.into_into_dart().into_dart(),
+            self.spent_by_transaction_id.into_into_dart().into_dart(),
+            self.spent_height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::outputs::OutputDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::outputs::OutputDto>
+    for crate::api::outputs::OutputDto
+{
+    fn into_into_dart(self) -> crate::api::outputs::OutputDto {
+        self
     }
 }
-
-impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::outputs::OutputFilterDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.statuses.into_into_dart().into_dart(),
+            self.min_value.into_into_dart().into_dart(),
+            self.max_value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::outputs::OutputFilterDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::outputs::OutputFilterDto>
+    for crate::api::outputs::OutputFilterDto
+{
+    fn into_into_dart(self) -> crate::api::outputs::OutputFilterDto {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::transactions::DisplayedTransactionDto> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::transactions::DisplayedTransactionDto>::sse_decode(deserializer),
-            );
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::outputs::OutputStatusDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Unspent => 0.into_dart(),
+            Self::Locked => 1.into_dart(),
+            Self::Spent => 2.into_dart(),
+            Self::Pending => 3.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::outputs::OutputStatusDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::outputs::OutputStatusDto>
+    for crate::api::outputs::OutputStatusDto
+{
+    fn into_into_dart(self) -> crate::api::outputs::OutputStatusDto {
+        self
     }
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::payment_uri::PaymentRequestDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.recipient_address.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.payment_id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<crate::api::transactions::CounterpartyInfoDto> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::transactions::CounterpartyInfoDto>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::payment_uri::PaymentRequestDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::payment_uri::PaymentRequestDto>
+    for crate::api::payment_uri::PaymentRequestDto
+{
+    fn into_into_dart(self) -> crate::api::payment_uri::PaymentRequestDto {
+        self
     }
 }
-
-impl SseDecode for Option<crate::api::transactions::FeeInfoDto> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::transactions::FeeInfoDto>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::proxy::ProxyKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Socks5 => 0.into_dart(),
+            Self::Http => 1.into_dart(),
+            _ => unreachable!(),
         }
     }
 }
-
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::proxy::ProxyKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy::ProxyKind>
+    for crate::api::proxy::ProxyKind
+{
+    fn into_into_dart(self) -> crate::api::proxy::ProxyKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::proxy::ProxySettingsDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.host.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::proxy::ProxySettingsDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy::ProxySettingsDto>
+    for crate::api::proxy::ProxySettingsDto
+{
+    fn into_into_dart(self) -> crate::api::proxy::ProxySettingsDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanConfiguration {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.password.into_into_dart().into_dart(),
+            self.base_url.into_into_dart().into_dart(),
+            self.batch_size.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.continuous.into_into_dart().into_dart(),
+            self.poll_interval_seconds.into_into_dart().into_dart(),
+            self.account_id.into_into_dart().into_dart(),
+            self.proxy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::scanner::ScanConfiguration {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_password = <String>::sse_decode(deserializer);
-        let mut var_baseUrl = <String>::sse_decode(deserializer);
-        let mut var_batchSize = <u64>::sse_decode(deserializer);
-        let mut var_continuous = <bool>::sse_decode(deserializer);
-        let mut var_pollIntervalSeconds = <u64>::sse_decode(deserializer);
-        return crate::api::scanner::ScanConfiguration {
-            password: var_password,
-            base_url: var_baseUrl,
-            batch_size: var_batchSize,
-            continuous: var_continuous,
-            poll_interval_seconds: var_pollIntervalSeconds,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanConfiguration
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanConfiguration>
+    for crate::api::scanner::ScanConfiguration
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanConfiguration {
+        self
     }
 }
-
-impl SseDecode for crate::api::scanner::ScanEventDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <crate::api::scanner::ScanStatusDto>::sse_decode(deserializer);
-                return crate::api::scanner::ScanEventDto::Status(var_field0);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanEventDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scanner::ScanEventDto::Status(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
-            1 => {
-                let mut var_field0 =
-                    <crate::api::scanner::TransactionsReadyDto>::sse_decode(deserializer);
-                return crate::api::scanner::ScanEventDto::TransactionsReady(var_field0);
+            crate::api::scanner::ScanEventDto::TransactionsReady(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
-            2 => {
-                let mut var_field0 =
-                    <crate::api::scanner::TransactionsUpdatedDto>::sse_decode(deserializer);
-                return crate::api::scanner::ScanEventDto::TransactionsUpdated(var_field0);
+            crate::api::scanner::ScanEventDto::TransactionsUpdated(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
-            3 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::scanner::ScanEventDto::Error(var_field0);
+            crate::api::scanner::ScanEventDto::NodeChanged { from_url, to_url } => [
+                3.into_dart(),
+                from_url.into_into_dart().into_dart(),
+                to_url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scanner::ScanEventDto::Error(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
             _ => {
                 unimplemented!("");
@@ -741,815 +4776,1029 @@ impl SseDecode for crate::api::scanner::ScanEventDto {
         }
     }
 }
-
-impl SseDecode for crate::api::scanner::ScanStatusDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_accountId = <i64>::sse_decode(deserializer);
-                let mut var_fromHeight = <u64>::sse_decode(deserializer);
-                return crate::api::scanner::ScanStatusDto::Started {
-                    account_id: var_accountId,
-                    from_height: var_fromHeight,
-                };
-            }
-            1 => {
-                let mut var_accountId = <i64>::sse_decode(deserializer);
-                let mut var_currentHeight = <u64>::sse_decode(deserializer);
-                let mut var_blocksScanned = <u64>::sse_decode(deserializer);
-                return crate::api::scanner::ScanStatusDto::Progress {
-                    account_id: var_accountId,
-                    current_height: var_currentHeight,
-                    blocks_scanned: var_blocksScanned,
-                };
-            }
-            2 => {
-                let mut var_accountId = <i64>::sse_decode(deserializer);
-                let mut var_finalHeight = <u64>::sse_decode(deserializer);
-                let mut var_totalBlocksScanned = <u64>::sse_decode(deserializer);
-                return crate::api::scanner::ScanStatusDto::Completed {
-                    account_id: var_accountId,
-                    final_height: var_finalHeight,
-                    total_blocks_scanned: var_totalBlocksScanned,
-                };
-            }
-            3 => {
-                let mut var_accountId = <i64>::sse_decode(deserializer);
-                let mut var_lastScannedHeight = <u64>::sse_decode(deserializer);
-                let mut var_reason = <String>::sse_decode(deserializer);
-                return crate::api::scanner::ScanStatusDto::Paused {
-                    account_id: var_accountId,
-                    last_scanned_height: var_lastScannedHeight,
-                    reason: var_reason,
-                };
-            }
-            4 => {
-                let mut var_accountId = <i64>::sse_decode(deserializer);
-                let mut var_resumeInSeconds = <u64>::sse_decode(deserializer);
-                return crate::api::scanner::ScanStatusDto::Waiting {
-                    account_id: var_accountId,
-                    resume_in_seconds: var_resumeInSeconds,
-                };
-            }
-            5 => {
-                let mut var_accountId = <i64>::sse_decode(deserializer);
-                let mut var_lastScannedHeight = <u64>::sse_decode(deserializer);
-                return crate::api::scanner::ScanStatusDto::MoreBlocksAvailable {
-                    account_id: var_accountId,
-                    last_scanned_height: var_lastScannedHeight,
-                };
-            }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanEventDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanEventDto>
+    for crate::api::scanner::ScanEventDto
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanEventDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanStatusDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scanner::ScanStatusDto::Started {
+                account_id,
+                from_height,
+            } => [
+                0.into_dart(),
+                account_id.into_into_dart().into_dart(),
+                from_height.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scanner::ScanStatusDto::Progress {
+                account_id,
+                current_height,
+                blocks_scanned,
+            } => [
+                1.into_dart(),
+                account_id.into_into_dart().into_dart(),
+                current_height.into_into_dart().into_dart(),
+                blocks_scanned.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scanner::ScanStatusDto::Completed {
+                account_id,
+                final_height,
+                total_blocks_scanned,
+            } => [
+                2.into_dart(),
+                account_id.into_into_dart().into_dart(),
+                final_height.into_into_dart().into_dart(),
+                total_blocks_scanned.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scanner::ScanStatusDto::Paused {
+                account_id,
+                last_scanned_height,
+                reason,
+            } => [
+                3.into_dart(),
+                account_id.into_into_dart().into_dart(),
+                last_scanned_height.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scanner::ScanStatusDto::Waiting {
+                account_id,
+                resume_in_seconds,
+            } => [
+                4.into_dart(),
+                account_id.into_into_dart().into_dart(),
+                resume_in_seconds.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scanner::ScanStatusDto::MoreBlocksAvailable {
+                account_id,
+                last_scanned_height,
+            } => [
+                5.into_dart(),
+                account_id.into_into_dart().into_dart(),
+                last_scanned_height.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
             _ => {
                 unimplemented!("");
             }
         }
     }
 }
-
-impl SseDecode for crate::api::send_transaction::SendTransactionDetails {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_seedWords = <Vec<String>>::sse_decode(deserializer);
-        let mut var_passphrase = <Option<String>>::sse_decode(deserializer);
-        let mut var_network = <Option<String>>::sse_decode(deserializer);
-        let mut var_baseUrl = <Option<String>>::sse_decode(deserializer);
-        let mut var_walletName = <Option<String>>::sse_decode(deserializer);
-        let mut var_recipientAddress = <String>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_paymentId = <Option<String>>::sse_decode(deserializer);
-        let mut var_confirmationWindow = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::send_transaction::SendTransactionDetails {
-            seed_words: var_seedWords,
-            passphrase: var_passphrase,
-            network: var_network,
-            base_url: var_baseUrl,
-            wallet_name: var_walletName,
-            recipient_address: var_recipientAddress,
-            amount: var_amount,
-            payment_id: var_paymentId,
-            confirmation_window: var_confirmationWindow,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanStatusDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanStatusDto>
+    for crate::api::scanner::ScanStatusDto
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanStatusDto {
+        self
     }
 }
-
-impl SseDecode for crate::api::send_transaction::SendTransactionEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_stage =
-            <crate::api::send_transaction::TransactionStage>::sse_decode(deserializer);
-        let mut var_details = <String>::sse_decode(deserializer);
-        return crate::api::send_transaction::SendTransactionEvent {
-            stage: var_stage,
-            details: var_details,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::seeds::SeedLanguage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::English => 0.into_dart(),
+            Self::ChineseSimplified => 1.into_dart(),
+            Self::French => 2.into_dart(),
+            Self::Italian => 3.into_dart(),
+            Self::Japanese => 4.into_dart(),
+            Self::Korean => 5.into_dart(),
+            Self::Spanish => 6.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for crate::api::send_transaction::TransactionStage {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::send_transaction::TransactionStage::Initializing,
-            1 => crate::api::send_transaction::TransactionStage::ValidatingInput,
-            2 => crate::api::send_transaction::TransactionStage::ConnectingToNetwork,
-            3 => crate::api::send_transaction::TransactionStage::FetchingBalance,
-            4 => crate::api::send_transaction::TransactionStage::ConstructingTransaction,
-            5 => crate::api::send_transaction::TransactionStage::SigningKeyGeneration,
-            6 => crate::api::send_transaction::TransactionStage::SigningTransaction,
-            7 => crate::api::send_transaction::TransactionStage::Broadcasting,
-            8 => crate::api::send_transaction::TransactionStage::Completed,
-            _ => unreachable!("Invalid variant for TransactionStage: {}", inner),
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::seeds::SeedLanguage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::seeds::SeedLanguage>
+    for crate::api::seeds::SeedLanguage
+{
+    fn into_into_dart(self) -> crate::api::seeds::SeedLanguage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::seeds::SeedValidationDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.language.into_into_dart().into_dart(),
+            self.words.into_into_dart().into_dart(),
+            self.unknown_word_indices.into_into_dart().into_dart(),
+            self.has_expected_length.into_into_dart().into_dart(),
+            self.checksum_valid.into_into_dart().into_dart(),
+            self.decryption_succeeded.into_into_dart().into_dart(),
+            self.birthday.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::seeds::SeedValidationDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::seeds::SeedValidationDto>
+    for crate::api::seeds::SeedValidationDto
+{
+    fn into_into_dart(self) -> crate::api::seeds::SeedValidationDto {
+        self
     }
 }
-
-impl SseDecode for crate::api::scanner::TransactionsReadyDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_accountId = <i64>::sse_decode(deserializer);
-        let mut var_transactions =
-            <Vec<crate::api::transactions::DisplayedTransactionDto>>::sse_decode(deserializer);
-        let mut var_blockHeight = <Option<u64>>::sse_decode(deserializer);
-        let mut var_isInitialSync = <bool>::sse_decode(deserializer);
-        return crate::api::scanner::TransactionsReadyDto {
-            account_id: var_accountId,
-            transactions: var_transactions,
-            block_height: var_blockHeight,
-            is_initial_sync: var_isInitialSync,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::seeds::SeedWordValidationDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.word.into_into_dart().into_dart(),
+            self.is_valid.into_into_dart().into_dart(),
+            self.suggestions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::scanner::TransactionsUpdatedDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_accountId = <i64>::sse_decode(deserializer);
-        let mut var_updatedTransactions =
-            <Vec<crate::api::transactions::DisplayedTransactionDto>>::sse_decode(deserializer);
-        return crate::api::scanner::TransactionsUpdatedDto {
-            account_id: var_accountId,
-            updated_transactions: var_updatedTransactions,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::seeds::SeedWordValidationDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::seeds::SeedWordValidationDto>
+    for crate::api::seeds::SeedWordValidationDto
+{
+    fn into_into_dart(self) -> crate::api::seeds::SeedWordValidationDto {
+        self
     }
 }
-
-impl SseDecode for u16 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::send_transaction::SendTransactionDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.seed_words.into_into_dart().into_dart(),
+            self.passphrase.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.base_url.into_into_dart().into_dart(),
+            self.wallet_name.into_into_dart().into_dart(),
+            self.account_id.into_into_dart().into_dart(),
+            self.recipient_address.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.payment_id.into_into_dart().into_dart(),
+            self.confirmation_window.into_into_dart().into_dart(),
+            self.proxy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::send_transaction::SendTransactionDetails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::send_transaction::SendTransactionDetails>
+    for crate::api::send_transaction::SendTransactionDetails
+{
+    fn into_into_dart(self) -> crate::api::send_transaction::SendTransactionDetails {
+        self
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::send_transaction::SendTransactionEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stage.into_into_dart().into_dart(),
+            self.details.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::send_transaction::SendTransactionEvent
+{
 }
-
-impl SseDecode for crate::api::wallet::WalletCreationDetails {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_tariAddress = <String>::sse_decode(deserializer);
-        let mut var_walletBirthday = <u16>::sse_decode(deserializer);
-        let mut var_spendPublicKeyHex = <String>::sse_decode(deserializer);
-        let mut var_viewPrivateKeyHex = <String>::sse_decode(deserializer);
-        return crate::api::wallet::WalletCreationDetails {
-            tari_address: var_tariAddress,
-            wallet_birthday: var_walletBirthday,
-            spend_public_key_hex: var_spendPublicKeyHex,
-            view_private_key_hex: var_viewPrivateKeyHex,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::send_transaction::SendTransactionEvent>
+    for crate::api::send_transaction::SendTransactionEvent
+{
+    fn into_into_dart(self) -> crate::api::send_transaction::SendTransactionEvent {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__wallet__create_wallet_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__db__disconnect_database_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__address__get_address_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__balance__get_balance_impl(port, ptr, rust_vec_len, data_len),
-        5 => {
-            wire__crate__api__transactions__get_transactions_impl(port, ptr, rust_vec_len, data_len)
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::send_transaction::TransactionStage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Initializing => 0.into_dart(),
+            Self::ValidatingInput => 1.into_dart(),
+            Self::ConnectingToNetwork => 2.into_dart(),
+            Self::FetchingBalance => 3.into_dart(),
+            Self::ConstructingTransaction => 4.into_dart(),
+            Self::SigningKeyGeneration => 5.into_dart(),
+            Self::SigningTransaction => 6.into_dart(),
+            Self::Broadcasting => 7.into_dart(),
+            Self::Completed => 8.into_dart(),
+            _ => unreachable!(),
         }
-        6 => wire__crate__api__db__initialize_database_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__seeds__list_words_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__wallet__restore_wallet_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__send_transaction__send_transaction_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__scanner__start_scan_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__scanner__stop_scan_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::send_transaction::TransactionStage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::send_transaction::TransactionStage>
+    for crate::api::send_transaction::TransactionStage
+{
+    fn into_into_dart(self) -> crate::api::send_transaction::TransactionStage {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::balance::AccountBalanceDto {
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::TransactionsReadyDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.total.into_into_dart().into_dart(),
-            self.unconfirmed.into_into_dart().into_dart(),
-            self.locked.into_into_dart().into_dart(),
-            self.available.into_into_dart().into_dart(),
+            self.account_id.into_into_dart().into_dart(),
+            self.transactions.into_into_dart().into_dart(),
+            self.block_height.into_into_dart().into_dart(),
+            self.is_initial_sync.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::balance::AccountBalanceDto
+    for crate::api::scanner::TransactionsReadyDto
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::balance::AccountBalanceDto>
-    for crate::api::balance::AccountBalanceDto
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::TransactionsReadyDto>
+    for crate::api::scanner::TransactionsReadyDto
 {
-    fn into_into_dart(self) -> crate::api::balance::AccountBalanceDto {
+    fn into_into_dart(self) -> crate::api::scanner::TransactionsReadyDto {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::transactions::BlockchainInfoDto {
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::TransactionsUpdatedDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.block_height.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-            self.confirmations.into_into_dart().into_dart(),
+            self.account_id.into_into_dart().into_dart(),
+            self.updated_transactions.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::transactions::BlockchainInfoDto
+    for crate::api::scanner::TransactionsUpdatedDto
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::BlockchainInfoDto>
-    for crate::api::transactions::BlockchainInfoDto
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::TransactionsUpdatedDto>
+    for crate::api::scanner::TransactionsUpdatedDto
 {
-    fn into_into_dart(self) -> crate::api::transactions::BlockchainInfoDto {
+    fn into_into_dart(self) -> crate::api::scanner::TransactionsUpdatedDto {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::transactions::CounterpartyInfoDto {
+impl flutter_rust_bridge::IntoDart for crate::api::address::WalletAddressDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.address.into_into_dart().into_dart(),
-            self.address_emoji.into_into_dart().into_dart(),
-            self.label.into_into_dart().into_dart(),
+            self.base58.into_into_dart().into_dart(),
+            self.emoji.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::transactions::CounterpartyInfoDto
+    for crate::api::address::WalletAddressDto
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::CounterpartyInfoDto>
-    for crate::api::transactions::CounterpartyInfoDto
+impl flutter_rust_bridge::IntoIntoDart<crate::api::address::WalletAddressDto>
+    for crate::api::address::WalletAddressDto
 {
-    fn into_into_dart(self) -> crate::api::transactions::CounterpartyInfoDto {
+    fn into_into_dart(self) -> crate::api::address::WalletAddressDto {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::transactions::DisplayedTransactionDto {
+impl flutter_rust_bridge::IntoDart for crate::api::wallet::WalletCreationDetails {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.direction.into_into_dart().into_dart(),
-            self.source.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-            self.amount.into_into_dart().into_dart(),
-            self.amount_display.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.counterparty.into_into_dart().into_dart(),
-            self.blockchain.into_into_dart().into_dart(),
-            self.fee.into_into_dart().into_dart(),
+            self.tari_address.into_into_dart().into_dart(),
+            self.wallet_birthday.into_into_dart().into_dart(),
+            self.spend_public_key_hex.into_into_dart().into_dart(),
+            self.view_private_key_hex.into_into_dart().into_dart(),
+            self.seed_words.into_into_dart().into_dart(),
+            self.seed_language.into_into_dart().into_dart(),
+            self.estimated_scan_start_height
+                .into_into_dart()
+                .into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::transactions::DisplayedTransactionDto
+    for crate::api::wallet::WalletCreationDetails
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::DisplayedTransactionDto>
-    for crate::api::transactions::DisplayedTransactionDto
+impl flutter_rust_bridge::IntoIntoDart<crate::api::wallet::WalletCreationDetails>
+    for crate::api::wallet::WalletCreationDetails
 {
-    fn into_into_dart(self) -> crate::api::transactions::DisplayedTransactionDto {
+    fn into_into_dart(self) -> crate::api::wallet::WalletCreationDetails {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::transactions::FeeInfoDto {
+impl flutter_rust_bridge::IntoDart for crate::api::wallet::WalletInfoDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.amount.into_into_dart().into_dart(),
-            self.amount_display.into_into_dart().into_dart(),
+            self.wallet_name.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.wallet_birthday.into_into_dart().into_dart(),
+            self.estimated_scan_start_height
+                .into_into_dart()
+                .into_dart(),
+            self.is_backed_up.into_into_dart().into_dart(),
+            self.is_watch_only.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::transactions::FeeInfoDto
+    for crate::api::wallet::WalletInfoDto
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::transactions::FeeInfoDto>
-    for crate::api::transactions::FeeInfoDto
+impl flutter_rust_bridge::IntoIntoDart<crate::api::wallet::WalletInfoDto>
+    for crate::api::wallet::WalletInfoDto
 {
-    fn into_into_dart(self) -> crate::api::transactions::FeeInfoDto {
+    fn into_into_dart(self) -> crate::api::wallet::WalletInfoDto {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanConfiguration {
+impl flutter_rust_bridge::IntoDart for crate::api::portfolio::WalletPortfolioDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.password.into_into_dart().into_dart(),
-            self.base_url.into_into_dart().into_dart(),
-            self.batch_size.into_into_dart().into_dart(),
-            self.continuous.into_into_dart().into_dart(),
-            self.poll_interval_seconds.into_into_dart().into_dart(),
+            self.wallet_name.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.accounts.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scanner::ScanConfiguration
+    for crate::api::portfolio::WalletPortfolioDto
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanConfiguration>
-    for crate::api::scanner::ScanConfiguration
+impl flutter_rust_bridge::IntoIntoDart<crate::api::portfolio::WalletPortfolioDto>
+    for crate::api::portfolio::WalletPortfolioDto
 {
-    fn into_into_dart(self) -> crate::api::scanner::ScanConfiguration {
+    fn into_into_dart(self) -> crate::api::portfolio::WalletPortfolioDto {
         self
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanEventDto {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::scanner::ScanEventDto::Status(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::scanner::ScanEventDto::TransactionsReady(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::scanner::ScanEventDto::TransactionsUpdated(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::scanner::ScanEventDto::Error(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scanner::ScanEventDto
+
+impl SseEncode
+    for StreamSink<
+        crate::api::balance::AccountBalanceDto,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanEventDto>
-    for crate::api::scanner::ScanEventDto
+
+impl SseEncode
+    for StreamSink<crate::api::scanner::ScanEventDto, flutter_rust_bridge::for_generated::SseCodec>
 {
-    fn into_into_dart(self) -> crate::api::scanner::ScanEventDto {
-        self
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanStatusDto {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::scanner::ScanStatusDto::Started {
-                account_id,
-                from_height,
-            } => [
-                0.into_dart(),
-                account_id.into_into_dart().into_dart(),
-                from_height.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::scanner::ScanStatusDto::Progress {
-                account_id,
-                current_height,
-                blocks_scanned,
-            } => [
-                1.into_dart(),
-                account_id.into_into_dart().into_dart(),
-                current_height.into_into_dart().into_dart(),
-                blocks_scanned.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::scanner::ScanStatusDto::Completed {
-                account_id,
-                final_height,
-                total_blocks_scanned,
-            } => [
-                2.into_dart(),
-                account_id.into_into_dart().into_dart(),
-                final_height.into_into_dart().into_dart(),
-                total_blocks_scanned.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::scanner::ScanStatusDto::Paused {
-                account_id,
-                last_scanned_height,
-                reason,
-            } => [
-                3.into_dart(),
-                account_id.into_into_dart().into_dart(),
-                last_scanned_height.into_into_dart().into_dart(),
-                reason.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::scanner::ScanStatusDto::Waiting {
-                account_id,
-                resume_in_seconds,
-            } => [
-                4.into_dart(),
-                account_id.into_into_dart().into_dart(),
-                resume_in_seconds.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::scanner::ScanStatusDto::MoreBlocksAvailable {
-                account_id,
-                last_scanned_height,
-            } => [
-                5.into_dart(),
-                account_id.into_into_dart().into_dart(),
-                last_scanned_height.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            _ => {
-                unimplemented!("");
-            }
-        }
+
+impl SseEncode
+    for StreamSink<
+        crate::api::send_transaction::SendTransactionEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::balance::AccountBalanceDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.total, serializer);
+        <u64>::sse_encode(self.unconfirmed, serializer);
+        <u64>::sse_encode(self.locked, serializer);
+        <u64>::sse_encode(self.available, serializer);
+    }
+}
+
+impl SseEncode for crate::api::accounts::AccountDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.account_id, serializer);
+        <String>::sse_encode(self.wallet_name, serializer);
+        <crate::api::balance::AccountBalanceDto>::sse_encode(self.balance, serializer);
+    }
+}
+
+impl SseEncode for crate::api::integrated_addresses::AddressFeaturesDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.one_sided, serializer);
+        <bool>::sse_encode(self.interactive, serializer);
+    }
+}
+
+impl SseEncode for crate::api::address::AddressFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::address::AddressFormat::Base58 => 0,
+                crate::api::address::AddressFormat::Emoji => 1,
+                crate::api::address::AddressFormat::Hex => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::address::AddressInspectionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_valid, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <crate::api::address::AddressFormat>::sse_encode(self.format, serializer);
+        <bool>::sse_encode(self.checksum_valid, serializer);
+        <Option<String>>::sse_encode(self.network, serializer);
+        <Option<bool>>::sse_encode(self.network_matches, serializer);
+        <bool>::sse_encode(self.is_dual_address, serializer);
+        <bool>::sse_encode(self.supports_one_sided, serializer);
+        <bool>::sse_encode(self.supports_interactive, serializer);
+        <bool>::sse_encode(self.has_payment_id, serializer);
+        <Option<String>>::sse_encode(self.payment_id, serializer);
+        <Option<String>>::sse_encode(self.payment_id_hex, serializer);
+        <Option<String>>::sse_encode(self.public_view_key_hex, serializer);
+        <Option<String>>::sse_encode(self.public_spend_key_hex, serializer);
+        <Option<String>>::sse_encode(self.base58, serializer);
+        <Option<String>>::sse_encode(self.emoji, serializer);
+    }
+}
+
+impl SseEncode for crate::api::balance::BalanceBreakdownDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.account_id, serializer);
+        <u64>::sse_encode(self.tip_height, serializer);
+        <u64>::sse_encode(self.confirmation_window, serializer);
+        <u64>::sse_encode(self.available, serializer);
+        <crate::api::balance::BalanceBucketDto>::sse_encode(self.pending_incoming, serializer);
+        <crate::api::balance::BalanceBucketDto>::sse_encode(self.pending_outgoing, serializer);
+        <crate::api::balance::BalanceBucketDto>::sse_encode(self.locked, serializer);
+        <crate::api::balance::BalanceBucketDto>::sse_encode(self.immature_coinbase, serializer);
+        <crate::api::balance::BalanceBucketDto>::sse_encode(self.time_locked, serializer);
+    }
+}
+
+impl SseEncode for crate::api::balance::BalanceBucketDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.total, serializer);
+        <Vec<crate::api::balance::BalanceEntryDto>>::sse_encode(self.entries, serializer);
+    }
+}
+
+impl SseEncode for crate::api::balance::BalanceEntryDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.amount, serializer);
+        <Option<String>>::sse_encode(self.output_hash, serializer);
+        <Option<String>>::sse_encode(self.transaction_id, serializer);
+        <Option<u64>>::sse_encode(self.spendable_at_height, serializer);
+        <Option<String>>::sse_encode(self.spendable_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::balance::BalanceGranularity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::balance::BalanceGranularity::Block => 0,
+                crate::api::balance::BalanceGranularity::Day => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::balance::BalancePointDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.block_height, serializer);
+        <Option<String>>::sse_encode(self.date, serializer);
+        <u64>::sse_encode(self.balance, serializer);
+        <u64>::sse_encode(self.received, serializer);
+        <u64>::sse_encode(self.spent, serializer);
+    }
+}
+
+impl SseEncode for crate::api::transactions::BlockchainInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.block_height, serializer);
+        <String>::sse_encode(self.timestamp, serializer);
+        <u64>::sse_encode(self.confirmations, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::contacts::ContactDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.address_emoji, serializer);
+        <Option<String>>::sse_encode(self.notes, serializer);
+        <bool>::sse_encode(self.is_favourite, serializer);
+        <String>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::transactions::CounterpartyInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <Option<String>>::sse_encode(self.address_emoji, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scanner::ScanStatusDto
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanStatusDto>
-    for crate::api::scanner::ScanStatusDto
-{
-    fn into_into_dart(self) -> crate::api::scanner::ScanStatusDto {
-        self
+
+impl SseEncode for crate::api::transactions::DisplayedTransactionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.direction, serializer);
+        <String>::sse_encode(self.source, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <String>::sse_encode(self.amount_display, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+        <Option<crate::api::transactions::CounterpartyInfoDto>>::sse_encode(
+            self.counterparty,
+            serializer,
+        );
+        <crate::api::transactions::BlockchainInfoDto>::sse_encode(self.blockchain, serializer);
+        <Option<crate::api::transactions::FeeInfoDto>>::sse_encode(self.fee, serializer);
+        <Option<crate::api::integrated_addresses::IntegratedAddressDto>>::sse_encode(
+            self.integrated_address,
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::send_transaction::SendTransactionDetails {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.seed_words.into_into_dart().into_dart(),
-            self.passphrase.into_into_dart().into_dart(),
-            self.network.into_into_dart().into_dart(),
-            self.base_url.into_into_dart().into_dart(),
-            self.wallet_name.into_into_dart().into_dart(),
-            self.recipient_address.into_into_dart().into_dart(),
-            self.amount.into_into_dart().into_dart(),
-            self.payment_id.into_into_dart().into_dart(),
-            self.confirmation_window.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::transactions::FeeInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.amount, serializer);
+        <String>::sse_encode(self.amount_display, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::send_transaction::SendTransactionDetails
-{
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::send_transaction::SendTransactionDetails>
-    for crate::api::send_transaction::SendTransactionDetails
-{
-    fn into_into_dart(self) -> crate::api::send_transaction::SendTransactionDetails {
-        self
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::send_transaction::SendTransactionEvent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.stage.into_into_dart().into_dart(),
-            self.details.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::integrated_addresses::IntegratedAddressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.wallet_name, serializer);
+        <i64>::sse_encode(self.account_id, serializer);
+        <String>::sse_encode(self.payment_id, serializer);
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.address_emoji, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.created_at, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::send_transaction::SendTransactionEvent
-{
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::send_transaction::SendTransactionEvent>
-    for crate::api::send_transaction::SendTransactionEvent
-{
-    fn into_into_dart(self) -> crate::api::send_transaction::SendTransactionEvent {
-        self
+
+impl SseEncode for Vec<crate::api::accounts::AccountDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::accounts::AccountDto>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::send_transaction::TransactionStage {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Initializing => 0.into_dart(),
-            Self::ValidatingInput => 1.into_dart(),
-            Self::ConnectingToNetwork => 2.into_dart(),
-            Self::FetchingBalance => 3.into_dart(),
-            Self::ConstructingTransaction => 4.into_dart(),
-            Self::SigningKeyGeneration => 5.into_dart(),
-            Self::SigningTransaction => 6.into_dart(),
-            Self::Broadcasting => 7.into_dart(),
-            Self::Completed => 8.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<crate::api::balance::BalanceEntryDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::balance::BalanceEntryDto>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::send_transaction::TransactionStage
-{
+
+impl SseEncode for Vec<crate::api::balance::BalancePointDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::balance::BalancePointDto>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::send_transaction::TransactionStage>
-    for crate::api::send_transaction::TransactionStage
-{
-    fn into_into_dart(self) -> crate::api::send_transaction::TransactionStage {
-        self
+
+impl SseEncode for Vec<crate::api::contacts::ContactDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::contacts::ContactDto>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scanner::TransactionsReadyDto {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.account_id.into_into_dart().into_dart(),
-            self.transactions.into_into_dart().into_dart(),
-            self.block_height.into_into_dart().into_dart(),
-            self.is_initial_sync.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::transactions::DisplayedTransactionDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::transactions::DisplayedTransactionDto>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scanner::TransactionsReadyDto
-{
+
+impl SseEncode for Vec<crate::api::integrated_addresses::IntegratedAddressDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::integrated_addresses::IntegratedAddressDto>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::TransactionsReadyDto>
-    for crate::api::scanner::TransactionsReadyDto
-{
-    fn into_into_dart(self) -> crate::api::scanner::TransactionsReadyDto {
-        self
+
+impl SseEncode for Vec<crate::api::portfolio::NetworkPortfolioDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::portfolio::NetworkPortfolioDto>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scanner::TransactionsUpdatedDto {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.account_id.into_into_dart().into_dart(),
-            self.updated_transactions.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::network_profiles::NetworkProfileDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::network_profiles::NetworkProfileDto>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scanner::TransactionsUpdatedDto
-{
+
+impl SseEncode for Vec<crate::api::node::NodeStatusDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::node::NodeStatusDto>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::TransactionsUpdatedDto>
-    for crate::api::scanner::TransactionsUpdatedDto
-{
-    fn into_into_dart(self) -> crate::api::scanner::TransactionsUpdatedDto {
-        self
+
+impl SseEncode for Vec<crate::api::offline_signing::OfflineRecipientDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::offline_signing::OfflineRecipientDto>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::wallet::WalletCreationDetails {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.tari_address.into_into_dart().into_dart(),
-            self.wallet_birthday.into_into_dart().into_dart(),
-            self.spend_public_key_hex.into_into_dart().into_dart(),
-            self.view_private_key_hex.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::outputs::OutputDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::outputs::OutputDto>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::wallet::WalletCreationDetails
-{
+
+impl SseEncode for Vec<crate::api::outputs::OutputStatusDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::outputs::OutputStatusDto>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::wallet::WalletCreationDetails>
-    for crate::api::wallet::WalletCreationDetails
-{
-    fn into_into_dart(self) -> crate::api::wallet::WalletCreationDetails {
-        self
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::api::scanner::ScanEventDto, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for Vec<crate::api::seeds::SeedWordValidationDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::seeds::SeedWordValidationDto>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::send_transaction::SendTransactionEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for Vec<crate::api::wallet::WalletInfoDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::wallet::WalletInfoDto>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::api::portfolio::WalletPortfolioDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::portfolio::WalletPortfolioDto>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::balance::AccountBalanceDto {
+impl SseEncode for crate::api::portfolio::NetworkPortfolioDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.total, serializer);
-        <u64>::sse_encode(self.unconfirmed, serializer);
-        <u64>::sse_encode(self.locked, serializer);
-        <u64>::sse_encode(self.available, serializer);
+        <String>::sse_encode(self.network, serializer);
+        <crate::api::balance::AccountBalanceDto>::sse_encode(self.total, serializer);
+        <Vec<crate::api::portfolio::WalletPortfolioDto>>::sse_encode(self.wallets, serializer);
     }
 }
 
-impl SseEncode for crate::api::transactions::BlockchainInfoDto {
+impl SseEncode for crate::api::network_profiles::NetworkProfileDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.block_height, serializer);
-        <String>::sse_encode(self.timestamp, serializer);
-        <u64>::sse_encode(self.confirmations, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.network, serializer);
+        <Option<String>>::sse_encode(self.base_url, serializer);
+        <u64>::sse_encode(self.batch_size, serializer);
+        <u64>::sse_encode(self.confirmation_window, serializer);
+        <bool>::sse_encode(self.is_customized, serializer);
+        <bool>::sse_encode(self.is_custom, serializer);
+        <bool>::sse_encode(self.is_active, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::node::NodeStatusDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.base_url, serializer);
+        <bool>::sse_encode(self.is_reachable, serializer);
+        <bool>::sse_encode(self.is_synced, serializer);
+        <Option<u64>>::sse_encode(self.tip_height, serializer);
+        <Option<u64>>::sse_encode(self.latency_ms, serializer);
+        <Option<String>>::sse_encode(self.version, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for crate::api::transactions::CounterpartyInfoDto {
+impl SseEncode for crate::api::offline_signing::OfflineRecipientDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.address, serializer);
-        <Option<String>>::sse_encode(self.address_emoji, serializer);
-        <Option<String>>::sse_encode(self.label, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <String>::sse_encode(self.payment_id, serializer);
     }
 }
 
-impl SseEncode for crate::api::transactions::DisplayedTransactionDto {
+impl SseEncode for crate::api::offline_signing::OfflineTransactionPayloadDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.direction, serializer);
-        <String>::sse_encode(self.source, serializer);
-        <String>::sse_encode(self.status, serializer);
-        <u64>::sse_encode(self.amount, serializer);
-        <String>::sse_encode(self.amount_display, serializer);
-        <Option<String>>::sse_encode(self.message, serializer);
-        <Option<crate::api::transactions::CounterpartyInfoDto>>::sse_encode(
-            self.counterparty,
+        <String>::sse_encode(self.json, serializer);
+        <Vec<String>>::sse_encode(self.qr_chunks, serializer);
+    }
+}
+
+impl SseEncode for crate::api::offline_signing::OfflineTransactionSummaryDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.network, serializer);
+        <String>::sse_encode(self.wallet_name, serializer);
+        <Vec<crate::api::offline_signing::OfflineRecipientDto>>::sse_encode(
+            self.recipients,
             serializer,
         );
-        <crate::api::transactions::BlockchainInfoDto>::sse_encode(self.blockchain, serializer);
-        <Option<crate::api::transactions::FeeInfoDto>>::sse_encode(self.fee, serializer);
+        <u64>::sse_encode(self.fee, serializer);
+        <u64>::sse_encode(self.total, serializer);
     }
 }
 
-impl SseEncode for crate::api::transactions::FeeInfoDto {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.amount, serializer);
-        <String>::sse_encode(self.amount_display, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<crate::api::integrated_addresses::AddressFeaturesDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::integrated_addresses::AddressFeaturesDto>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Option<crate::api::contacts::ContactDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::contacts::ContactDto>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::transactions::DisplayedTransactionDto> {
+impl SseEncode for Option<crate::api::transactions::CounterpartyInfoDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::transactions::DisplayedTransactionDto>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::transactions::CounterpartyInfoDto>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<crate::api::transactions::FeeInfoDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::transactions::FeeInfoDto>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <i64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::transactions::CounterpartyInfoDto> {
+impl SseEncode for Option<crate::api::integrated_addresses::IntegratedAddressDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::transactions::CounterpartyInfoDto>::sse_encode(value, serializer);
+            <crate::api::integrated_addresses::IntegratedAddressDto>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::transactions::FeeInfoDto> {
+impl SseEncode for Option<crate::api::outputs::OutputFilterDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::transactions::FeeInfoDto>::sse_encode(value, serializer);
+            <crate::api::outputs::OutputFilterDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::proxy::ProxySettingsDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::proxy::ProxySettingsDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::seeds::SeedLanguage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::seeds::SeedLanguage>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
         }
     }
 }
@@ -1564,14 +5813,122 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<String>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<crate::api::outputs::OutputStatusDto>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::api::outputs::OutputStatusDto>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::outputs::OutputDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.output_hash, serializer);
+        <String>::sse_encode(self.commitment, serializer);
+        <u64>::sse_encode(self.value, serializer);
+        <crate::api::outputs::OutputStatusDto>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.output_type, serializer);
+        <u64>::sse_encode(self.mined_height, serializer);
+        <u64>::sse_encode(self.maturity_height, serializer);
+        <bool>::sse_encode(self.is_mature, serializer);
+        <Option<String>>::sse_encode(self.lock_expires_at, serializer);
+        <Option<String>>::sse_encode(self.payment_id, serializer);
+        <Option<String>>::sse_encode(self.created_by_transaction_id, serializer);
+        <Option<String>>::sse_encode(self.locked_by_request_id, serializer);
+        <Option<String>>::sse_encode(self.spent_by_transaction_id, serializer);
+        <Option<u64>>::sse_encode(self.spent_height, serializer);
+    }
+}
+
+impl SseEncode for crate::api::outputs::OutputFilterDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<Vec<crate::api::outputs::OutputStatusDto>>>::sse_encode(self.statuses, serializer);
+        <Option<u64>>::sse_encode(self.min_value, serializer);
+        <Option<u64>>::sse_encode(self.max_value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::outputs::OutputStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::outputs::OutputStatusDto::Unspent => 0,
+                crate::api::outputs::OutputStatusDto::Locked => 1,
+                crate::api::outputs::OutputStatusDto::Spent => 2,
+                crate::api::outputs::OutputStatusDto::Pending => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::payment_uri::PaymentRequestDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.recipient_address, serializer);
+        <Option<u64>>::sse_encode(self.amount, serializer);
+        <Option<String>>::sse_encode(self.payment_id, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.network, serializer);
+    }
+}
+
+impl SseEncode for crate::api::proxy::ProxyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::proxy::ProxyKind::Socks5 => 0,
+                crate::api::proxy::ProxyKind::Http => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::proxy::ProxySettingsDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::proxy::ProxyKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.password, serializer);
+    }
+}
+
 impl SseEncode for crate::api::scanner::ScanConfiguration {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.password, serializer);
-        <String>::sse_encode(self.base_url, serializer);
-        <u64>::sse_encode(self.batch_size, serializer);
+        <Option<String>>::sse_encode(self.base_url, serializer);
+        <Option<u64>>::sse_encode(self.batch_size, serializer);
+        <Option<String>>::sse_encode(self.network, serializer);
         <bool>::sse_encode(self.continuous, serializer);
         <u64>::sse_encode(self.poll_interval_seconds, serializer);
+        <Option<i64>>::sse_encode(self.account_id, serializer);
+        <Option<crate::api::proxy::ProxySettingsDto>>::sse_encode(self.proxy, serializer);
     }
 }
 
@@ -1591,8 +5948,13 @@ impl SseEncode for crate::api::scanner::ScanEventDto {
                 <i32>::sse_encode(2, serializer);
                 <crate::api::scanner::TransactionsUpdatedDto>::sse_encode(field0, serializer);
             }
-            crate::api::scanner::ScanEventDto::Error(field0) => {
+            crate::api::scanner::ScanEventDto::NodeChanged { from_url, to_url } => {
                 <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(from_url, serializer);
+                <String>::sse_encode(to_url, serializer);
+            }
+            crate::api::scanner::ScanEventDto::Error(field0) => {
+                <i32>::sse_encode(4, serializer);
                 <String>::sse_encode(field0, serializer);
             }
             _ => {
@@ -1667,18 +6029,65 @@ impl SseEncode for crate::api::scanner::ScanStatusDto {
     }
 }
 
+impl SseEncode for crate::api::seeds::SeedLanguage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::seeds::SeedLanguage::English => 0,
+                crate::api::seeds::SeedLanguage::ChineseSimplified => 1,
+                crate::api::seeds::SeedLanguage::French => 2,
+                crate::api::seeds::SeedLanguage::Italian => 3,
+                crate::api::seeds::SeedLanguage::Japanese => 4,
+                crate::api::seeds::SeedLanguage::Korean => 5,
+                crate::api::seeds::SeedLanguage::Spanish => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::seeds::SeedValidationDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::seeds::SeedLanguage>::sse_encode(self.language, serializer);
+        <Vec<crate::api::seeds::SeedWordValidationDto>>::sse_encode(self.words, serializer);
+        <Vec<u32>>::sse_encode(self.unknown_word_indices, serializer);
+        <bool>::sse_encode(self.has_expected_length, serializer);
+        <bool>::sse_encode(self.checksum_valid, serializer);
+        <bool>::sse_encode(self.decryption_succeeded, serializer);
+        <Option<u16>>::sse_encode(self.birthday, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::seeds::SeedWordValidationDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.word, serializer);
+        <bool>::sse_encode(self.is_valid, serializer);
+        <Vec<String>>::sse_encode(self.suggestions, serializer);
+    }
+}
+
 impl SseEncode for crate::api::send_transaction::SendTransactionDetails {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<String>>::sse_encode(self.seed_words, serializer);
+        <Option<Vec<String>>>::sse_encode(self.seed_words, serializer);
         <Option<String>>::sse_encode(self.passphrase, serializer);
         <Option<String>>::sse_encode(self.network, serializer);
         <Option<String>>::sse_encode(self.base_url, serializer);
         <Option<String>>::sse_encode(self.wallet_name, serializer);
+        <Option<i64>>::sse_encode(self.account_id, serializer);
         <String>::sse_encode(self.recipient_address, serializer);
         <u64>::sse_encode(self.amount, serializer);
         <Option<String>>::sse_encode(self.payment_id, serializer);
         <Option<u64>>::sse_encode(self.confirmation_window, serializer);
+        <Option<crate::api::proxy::ProxySettingsDto>>::sse_encode(self.proxy, serializer);
     }
 }
 
@@ -1744,6 +6153,13 @@ impl SseEncode for u16 {
     }
 }
 
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1763,6 +6179,14 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::address::WalletAddressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.base58, serializer);
+        <String>::sse_encode(self.emoji, serializer);
+    }
+}
+
 impl SseEncode for crate::api::wallet::WalletCreationDetails {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1770,6 +6194,30 @@ impl SseEncode for crate::api::wallet::WalletCreationDetails {
         <u16>::sse_encode(self.wallet_birthday, serializer);
         <String>::sse_encode(self.spend_public_key_hex, serializer);
         <String>::sse_encode(self.view_private_key_hex, serializer);
+        <Vec<String>>::sse_encode(self.seed_words, serializer);
+        <crate::api::seeds::SeedLanguage>::sse_encode(self.seed_language, serializer);
+        <u64>::sse_encode(self.estimated_scan_start_height, serializer);
+    }
+}
+
+impl SseEncode for crate::api::wallet::WalletInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.wallet_name, serializer);
+        <String>::sse_encode(self.network, serializer);
+        <u16>::sse_encode(self.wallet_birthday, serializer);
+        <u64>::sse_encode(self.estimated_scan_start_height, serializer);
+        <bool>::sse_encode(self.is_backed_up, serializer);
+        <bool>::sse_encode(self.is_watch_only, serializer);
+    }
+}
+
+impl SseEncode for crate::api::portfolio::WalletPortfolioDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.wallet_name, serializer);
+        <crate::api::balance::AccountBalanceDto>::sse_encode(self.total, serializer);
+        <Vec<crate::api::accounts::AccountDto>>::sse_encode(self.accounts, serializer);
     }
 }
 
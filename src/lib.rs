@@ -1,5 +1,7 @@
 pub mod api;
 mod frb_generated;
+#[cfg(test)]
+mod test_support;
 
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
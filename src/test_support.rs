@@ -0,0 +1,140 @@
+//! Local stand-ins for the network services the API talks to.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Serves `/get_tip_info` and `/version` like a base node would, until the test ends.
+pub(crate) async fn stand_in_node(tip_info: &'static str, version: Option<&'static str>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+                let (status, body) = match (path, version) {
+                    ("/get_tip_info", _) => ("200 OK", tip_info),
+                    ("/version", Some(version)) => ("200 OK", version),
+                    _ => ("404 Not Found", "{}"),
+                };
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+                     Connection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    base_url
+}
+
+/// A URL nothing listens on, so connections are refused right away.
+pub(crate) async fn closed_port() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    format!("http://{}", listener.local_addr().unwrap())
+}
+
+/// A SOCKS5 proxy that records the `host:port` of every connection it relays.
+pub(crate) struct SocksStandIn {
+    pub addr: SocketAddr,
+    pub targets: Arc<Mutex<Vec<String>>>,
+}
+
+impl SocksStandIn {
+    pub fn targets(&self) -> Vec<String> {
+        self.targets.lock().unwrap().clone()
+    }
+}
+
+/// Starts a SOCKS5 proxy, requiring username/password authentication when `credentials` is set.
+pub(crate) async fn socks5_stand_in(
+    credentials: Option<(&'static str, &'static str)>,
+) -> SocksStandIn {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let targets = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = targets.clone();
+    tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            let recorded = recorded.clone();
+            tokio::spawn(async move {
+                let _ = relay_socks5(socket, credentials, recorded).await;
+            });
+        }
+    });
+
+    SocksStandIn { addr, targets }
+}
+
+async fn relay_socks5(
+    mut client: TcpStream,
+    credentials: Option<(&'static str, &'static str)>,
+    targets: Arc<Mutex<Vec<String>>>,
+) -> std::io::Result<()> {
+    let mut header = [0u8; 2];
+    client.read_exact(&mut header).await?;
+    let mut methods = vec![0u8; usize::from(header[1])];
+    client.read_exact(&mut methods).await?;
+
+    match credentials {
+        Some((username, password)) => {
+            if !methods.contains(&2) {
+                return client.write_all(&[5, 0xff]).await;
+            }
+            client.write_all(&[5, 2]).await?;
+            let given_username = read_socks_field(&mut client, true).await?;
+            let given_password = read_socks_field(&mut client, false).await?;
+            if given_username != username.as_bytes() || given_password != password.as_bytes() {
+                return client.write_all(&[1, 1]).await;
+            }
+            client.write_all(&[1, 0]).await?;
+        }
+        None => client.write_all(&[5, 0]).await?,
+    }
+
+    let mut request = [0u8; 4];
+    client.read_exact(&mut request).await?;
+    let host = match request[3] {
+        1 => {
+            let mut ip = [0u8; 4];
+            client.read_exact(&mut ip).await?;
+            std::net::Ipv4Addr::from(ip).to_string()
+        }
+        3 => String::from_utf8_lossy(&read_socks_field(&mut client, false).await?).into_owned(),
+        _ => return client.write_all(&[5, 8, 0, 1, 0, 0, 0, 0, 0, 0]).await,
+    };
+    let mut port = [0u8; 2];
+    client.read_exact(&mut port).await?;
+    let target = format!("{}:{}", host, u16::from_be_bytes(port));
+    targets.lock().unwrap().push(target.clone());
+
+    let mut upstream = TcpStream::connect(&target).await?;
+    client.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).await?;
+    tokio::io::copy_bidirectional(&mut client, &mut upstream).await?;
+    Ok(())
+}
+
+/// Reads a length-prefixed field, skipping the version byte that precedes the username.
+async fn read_socks_field(client: &mut TcpStream, versioned: bool) -> std::io::Result<Vec<u8>> {
+    if versioned {
+        client.read_u8().await?;
+    }
+    let len = client.read_u8().await?;
+    let mut field = vec![0u8; usize::from(len)];
+    client.read_exact(&mut field).await?;
+    Ok(field)
+}